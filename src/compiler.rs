@@ -0,0 +1,304 @@
+use crate::entities::complex::{
+    definition::WordElement,
+    expression::{Expression, ExpressionElement},
+    statement::{
+        BeginAgainStatement, BeginUntilStatement, BeginWhileRepeatStatement, CaseStatement,
+        DoLoopStatement, DoesStatement, IfElseThenStatement, IfThenStatement, OfClause, Statement,
+    },
+};
+use crate::errors::ForthError::{CompileOnly, ControlStructureMismatch};
+use crate::Result;
+
+/// The construct a control frame is collecting a body for, together with
+/// whatever its earlier parts have already produced.
+#[derive(Debug, Clone)]
+enum Construct {
+    Colon(Option<String>),
+    Quotation,
+    If,
+    Else(Vec<WordElement>),
+    Do(bool),
+    Begin,
+    While(Vec<WordElement>),
+    Case(Vec<OfClause>),
+    Of(Vec<OfClause>, Vec<WordElement>),
+    Does,
+}
+
+#[derive(Debug, Clone)]
+struct Frame {
+    construct: Construct,
+    body: Vec<WordElement>,
+}
+
+/// Builds the body of the definition being compiled. Control structures open
+/// nested frames which are turned into statements of the enclosing frame once
+/// they are closed.
+#[derive(Debug, Clone, Default)]
+pub struct Compiler {
+    frames: Vec<Frame>,
+    locals: Option<Vec<String>>,
+}
+
+impl Compiler {
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            frames: vec![],
+            locals: None,
+        }
+    }
+
+    #[inline]
+    pub fn reset(&mut self) {
+        self.frames.clear();
+        self.locals = None;
+    }
+
+    pub fn definition_name(&self) -> Option<&str> {
+        match self.frames.first() {
+            Some(Frame {
+                construct: Construct::Colon(Some(name)),
+                ..
+            }) => Some(name),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub fn is_compiling(&self) -> bool {
+        !self.frames.is_empty()
+    }
+
+    pub fn begin_definition(&mut self, name: String) {
+        self.reset();
+        self.frames.push(Frame {
+            construct: Construct::Colon(Some(name)),
+            body: vec![],
+        });
+    }
+
+    pub fn begin_anonymous_definition(&mut self) {
+        self.reset();
+        self.frames.push(Frame {
+            construct: Construct::Colon(None),
+            body: vec![],
+        });
+    }
+
+    /// Declares the locals of the current definition. This may be done once per
+    /// definition, outside of any control structure or quotation.
+    pub fn declare_locals(&mut self, names: Vec<String>) -> Result<()> {
+        match self.frames.last() {
+            None => Err(CompileOnly),
+            Some(Frame {
+                construct: Construct::Colon(_),
+                ..
+            }) if self.locals.is_none() => {
+                self.locals = Some(names);
+                Ok(())
+            }
+            Some(_) => Err(ControlStructureMismatch),
+        }
+    }
+
+    /// Returns the index of the local `name` in the frame of the current definition.
+    /// Quotations and `does>` bodies run without that frame, so they see no locals.
+    pub fn find_local(&self, name: &str) -> Option<usize> {
+        if self
+            .frames
+            .iter()
+            .any(|frame| matches!(frame.construct, Construct::Quotation | Construct::Does))
+        {
+            return None;
+        }
+        self.locals
+            .as_ref()?
+            .iter()
+            .rposition(|local| local == name)
+    }
+
+    /// Closes the definition, returning its name (`None` for `:noname`) and body.
+    pub fn end_definition(&mut self) -> Result<(Option<String>, Vec<WordElement>)> {
+        if let Some(Frame {
+            construct: Construct::Does,
+            ..
+        }) = self.frames.last()
+        {
+            let (_, body) = self.close()?;
+            self.compile_statement(Statement::Does(DoesStatement::new(body)))?;
+        }
+
+        match self.close()? {
+            (Construct::Colon(name), body) => {
+                self.locals = None;
+                Ok((name, body))
+            }
+            _ => Err(ControlStructureMismatch),
+        }
+    }
+
+    pub fn compile(&mut self, element: ExpressionElement) -> Result<()> {
+        let body = &mut self.frames.last_mut().ok_or(CompileOnly)?.body;
+        if let Some(WordElement::Expression(expression)) = body.last_mut() {
+            expression.push(element);
+        } else {
+            let mut expression = Expression::new();
+            expression.push(element);
+            body.push(WordElement::Expression(expression));
+        }
+        Ok(())
+    }
+
+    pub fn begin_quotation(&mut self) -> Result<()> {
+        self.open(Construct::Quotation)
+    }
+
+    pub fn end_quotation(&mut self) -> Result<Vec<WordElement>> {
+        match self.close()? {
+            (Construct::Quotation, body) => Ok(body),
+            _ => Err(ControlStructureMismatch),
+        }
+    }
+
+    pub fn compile_if(&mut self) -> Result<()> {
+        self.open(Construct::If)
+    }
+
+    pub fn compile_else(&mut self) -> Result<()> {
+        match self.close()? {
+            (Construct::If, true_body) => self.open(Construct::Else(true_body)),
+            _ => Err(ControlStructureMismatch),
+        }
+    }
+
+    pub fn compile_then(&mut self) -> Result<()> {
+        let statement = match self.close()? {
+            (Construct::If, body) => Statement::IfThen(IfThenStatement::new(body)),
+            (Construct::Else(true_body), body) => {
+                Statement::IfElseThen(IfElseThenStatement::new(true_body, body))
+            }
+            _ => return Err(ControlStructureMismatch),
+        };
+        self.compile_statement(statement)
+    }
+
+    pub fn compile_do(&mut self, conditional: bool) -> Result<()> {
+        self.open(Construct::Do(conditional))
+    }
+
+    pub fn compile_loop(&mut self, step: bool) -> Result<()> {
+        match self.close()? {
+            (Construct::Do(conditional), body) => self.compile_statement(Statement::DoLoop(
+                DoLoopStatement::new(conditional, body, step),
+            )),
+            _ => Err(ControlStructureMismatch),
+        }
+    }
+
+    pub fn compile_begin(&mut self) -> Result<()> {
+        self.open(Construct::Begin)
+    }
+
+    pub fn compile_until(&mut self) -> Result<()> {
+        match self.close()? {
+            (Construct::Begin, body) => {
+                self.compile_statement(Statement::BeginUntil(BeginUntilStatement::new(body)))
+            }
+            _ => Err(ControlStructureMismatch),
+        }
+    }
+
+    pub fn compile_again(&mut self) -> Result<()> {
+        match self.close()? {
+            (Construct::Begin, body) => {
+                self.compile_statement(Statement::BeginAgain(BeginAgainStatement::new(body)))
+            }
+            _ => Err(ControlStructureMismatch),
+        }
+    }
+
+    pub fn compile_while(&mut self) -> Result<()> {
+        match self.close()? {
+            (Construct::Begin, condition) => self.open(Construct::While(condition)),
+            _ => Err(ControlStructureMismatch),
+        }
+    }
+
+    pub fn compile_repeat(&mut self) -> Result<()> {
+        match self.close()? {
+            (Construct::While(condition), body) => self.compile_statement(
+                Statement::BeginWhileRepeat(BeginWhileRepeatStatement::new(condition, body)),
+            ),
+            _ => Err(ControlStructureMismatch),
+        }
+    }
+
+    pub fn compile_case(&mut self) -> Result<()> {
+        self.open(Construct::Case(vec![]))
+    }
+
+    pub fn compile_of(&mut self) -> Result<()> {
+        match self.close()? {
+            (Construct::Case(clauses), test) => self.open(Construct::Of(clauses, test)),
+            _ => Err(ControlStructureMismatch),
+        }
+    }
+
+    pub fn compile_endof(&mut self) -> Result<()> {
+        match self.close()? {
+            (Construct::Of(mut clauses, test), body) => {
+                clauses.push(OfClause::new(test, body));
+                self.open(Construct::Case(clauses))
+            }
+            _ => Err(ControlStructureMismatch),
+        }
+    }
+
+    pub fn compile_endcase(&mut self) -> Result<()> {
+        match self.close()? {
+            (Construct::Case(clauses), default_body) => {
+                self.compile_statement(Statement::Case(CaseStatement::new(clauses, default_body)))
+            }
+            _ => Err(ControlStructureMismatch),
+        }
+    }
+
+    /// Starts collecting the behavior `does>` attaches; only valid at the top level
+    /// of a definition.
+    pub fn compile_does(&mut self) -> Result<()> {
+        match self.frames.last() {
+            None => Err(CompileOnly),
+            Some(Frame {
+                construct: Construct::Colon(_),
+                ..
+            }) => self.open(Construct::Does),
+            Some(_) => Err(ControlStructureMismatch),
+        }
+    }
+
+    fn compile_statement(&mut self, statement: Statement) -> Result<()> {
+        self.frames
+            .last_mut()
+            .ok_or(ControlStructureMismatch)?
+            .body
+            .push(WordElement::Statement(statement));
+        Ok(())
+    }
+
+    fn open(&mut self, construct: Construct) -> Result<()> {
+        if self.frames.is_empty() {
+            return Err(CompileOnly);
+        }
+        self.frames.push(Frame {
+            construct,
+            body: vec![],
+        });
+        Ok(())
+    }
+
+    fn close(&mut self) -> Result<(Construct, Vec<WordElement>)> {
+        let frame = self.frames.pop().ok_or(CompileOnly)?;
+        Ok((frame.construct, frame.body))
+    }
+}
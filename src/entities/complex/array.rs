@@ -44,6 +44,13 @@ impl Array {
         }
     }
 
+    /// Appends a cell past the current end, enlarging the array.
+    #[inline]
+    pub fn grow(&mut self, value: Literal) {
+        self.storage.push(value);
+        self.capacity += 1;
+    }
+
     #[inline]
     pub fn capacity(&self) -> usize {
         self.capacity
@@ -62,3 +69,14 @@ impl IndexMut<usize> for Array {
         &mut self.storage[index]
     }
 }
+
+impl From<Vec<Literal>> for Array {
+    fn from(storage: Vec<Literal>) -> Self {
+        let size = storage.len();
+        Self {
+            storage,
+            size,
+            capacity: size,
+        }
+    }
+}
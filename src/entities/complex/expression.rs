@@ -1,5 +1,4 @@
-use crate::entities::simple::{ident::Ident, literal::Literal};
-use crate::parser::{Parse, Rule};
+use crate::entities::simple::{execution_token::ExecutionToken, ident::Ident, literal::Literal};
 
 use crate::{ExecuteExt, Result};
 
@@ -7,16 +6,15 @@ use crate::{ExecuteExt, Result};
 pub enum ExpressionElement {
     Literal(Literal),
     Ident(Ident),
-}
-
-impl Parse for ExpressionElement {
-    fn parse(pair: pest::iterators::Pair<Rule>) -> Self {
-        match pair.as_rule() {
-            Rule::literal => Self::Literal(Literal::parse(pair)),
-            Rule::ident => Self::Ident(Ident::parse(pair)),
-            _ => unreachable!(),
-        }
-    }
+    /// Runs the word of the token itself, however its name is defined by then.
+    Execute(ExecutionToken),
+    /// Compiles a call to the word into the current definition, as left behind by `postpone`.
+    Compile(Ident),
+    /// Opens the locals frame of the running word, taking the given number of initialized
+    /// locals from the stack and zeroing the given number of uninitialized ones.
+    EnterLocals(usize, usize),
+    Local(usize),
+    ToLocal(usize),
 }
 
 impl ExecuteExt for ExpressionElement {
@@ -28,31 +26,42 @@ impl ExecuteExt for ExpressionElement {
             Self::Ident(ident) => {
                 ident.execute(interpreter)?;
             }
+            Self::Execute(token) => {
+                token.execute(interpreter)?;
+            }
+            Self::Compile(ident) => {
+                interpreter.compiler.compile(Self::Ident(ident.clone()))?;
+            }
+            Self::EnterLocals(initialized, uninitialized) => {
+                interpreter.enter_locals(*initialized, *uninitialized)?;
+            }
+            Self::Local(index) => {
+                let value = interpreter.get_local(*index)?.clone();
+                interpreter.push(value);
+            }
+            Self::ToLocal(index) => {
+                let value = interpreter.get_unary_operand()?;
+                *interpreter.get_local(*index)? = value;
+            }
         }
         Ok(())
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 pub struct Expression {
     elements: Vec<ExpressionElement>,
 }
 
-impl Parse for Expression {
-    fn parse(pair: pest::iterators::Pair<Rule>) -> Self {
-        let mut elements: Vec<ExpressionElement> = vec![];
-        for inner_pair in pair.into_inner() {
-            match inner_pair.as_rule() {
-                Rule::literal => {
-                    elements.push(ExpressionElement::Literal(Literal::parse(inner_pair)));
-                }
-                Rule::ident => {
-                    elements.push(ExpressionElement::Ident(Ident::parse(inner_pair)));
-                }
-                _ => unreachable!(),
-            }
-        }
-        Self { elements }
+impl Expression {
+    #[inline]
+    pub fn new() -> Self {
+        Self { elements: vec![] }
+    }
+
+    #[inline]
+    pub fn push(&mut self, element: ExpressionElement) {
+        self.elements.push(element);
     }
 }
 
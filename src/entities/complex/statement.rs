@@ -1,6 +1,11 @@
-use crate::entities::{complex::expression::Expression, simple::ident::Ident};
+use crate::entities::complex::{
+    definition::WordElement,
+    expression::{Expression, ExpressionElement},
+};
 
-use crate::parser::{Parse, Rule};
+use std::{convert::TryFrom, sync::Arc};
+
+use crate::errors::ForthError;
 use crate::{entities::simple::literal::Literal, ExecuteExt, Result};
 
 #[derive(Debug, Clone)]
@@ -8,18 +13,11 @@ pub enum Statement {
     IfThen(IfThenStatement),
     IfElseThen(IfElseThenStatement),
     DoLoop(DoLoopStatement),
-}
-
-impl Parse for Statement {
-    fn parse(pair: pest::iterators::Pair<Rule>) -> Self {
-        let inner = pair.into_inner().next().unwrap();
-        match inner.as_rule() {
-            Rule::if_then_statement => Self::IfThen(IfThenStatement::parse(inner)),
-            Rule::if_else_then_statement => Self::IfElseThen(IfElseThenStatement::parse(inner)),
-            Rule::do_loop => Self::DoLoop(DoLoopStatement::parse(inner)),
-            _ => unreachable!(),
-        }
-    }
+    BeginUntil(BeginUntilStatement),
+    BeginWhileRepeat(BeginWhileRepeatStatement),
+    BeginAgain(BeginAgainStatement),
+    Case(CaseStatement),
+    Does(DoesStatement),
 }
 
 impl ExecuteExt for Statement {
@@ -34,6 +32,21 @@ impl ExecuteExt for Statement {
             Self::DoLoop(stmt) => {
                 stmt.execute(interpreter)?;
             }
+            Self::BeginUntil(stmt) => {
+                stmt.execute(interpreter)?;
+            }
+            Self::BeginWhileRepeat(stmt) => {
+                stmt.execute(interpreter)?;
+            }
+            Self::BeginAgain(stmt) => {
+                stmt.execute(interpreter)?;
+            }
+            Self::Case(stmt) => {
+                stmt.execute(interpreter)?;
+            }
+            Self::Does(stmt) => {
+                stmt.execute(interpreter)?;
+            }
         }
         Ok(())
     }
@@ -41,22 +54,20 @@ impl ExecuteExt for Statement {
 
 #[derive(Debug, Clone)]
 pub struct IfThenStatement {
-    true_expr: Expression,
+    true_body: Vec<WordElement>,
 }
 
-impl Parse for IfThenStatement {
-    fn parse(pair: pest::iterators::Pair<Rule>) -> Self {
-        let mut inner = pair.into_inner();
-        Self {
-            true_expr: Expression::parse(inner.next().unwrap()),
-        }
+impl IfThenStatement {
+    #[inline]
+    pub fn new(true_body: Vec<WordElement>) -> Self {
+        Self { true_body }
     }
 }
 
 impl ExecuteExt for IfThenStatement {
     fn execute(&self, interpreter: &mut crate::ForthInterpreter) -> Result<()> {
-        if interpreter.get_last_literal()?.into() {
-            self.true_expr.execute(interpreter)?;
+        if bool::try_from(&interpreter.get_unary_operand()?)? {
+            self.true_body.execute(interpreter)?;
         }
         Ok(())
     }
@@ -64,26 +75,26 @@ impl ExecuteExt for IfThenStatement {
 
 #[derive(Debug, Clone)]
 pub struct IfElseThenStatement {
-    true_expr: Expression,
-    false_expr: Expression,
+    true_body: Vec<WordElement>,
+    false_body: Vec<WordElement>,
 }
 
-impl Parse for IfElseThenStatement {
-    fn parse(pair: pest::iterators::Pair<Rule>) -> Self {
-        let mut inner = pair.into_inner();
+impl IfElseThenStatement {
+    #[inline]
+    pub fn new(true_body: Vec<WordElement>, false_body: Vec<WordElement>) -> Self {
         Self {
-            true_expr: Expression::parse(inner.next().unwrap()),
-            false_expr: Expression::parse(inner.next().unwrap()),
+            true_body,
+            false_body,
         }
     }
 }
 
 impl ExecuteExt for IfElseThenStatement {
     fn execute(&self, interpreter: &mut crate::ForthInterpreter) -> Result<()> {
-        if interpreter.get_last_literal()?.into() {
-            self.true_expr.execute(interpreter)?;
+        if bool::try_from(&interpreter.get_unary_operand()?)? {
+            self.true_body.execute(interpreter)?;
         } else {
-            self.false_expr.execute(interpreter)?;
+            self.false_body.execute(interpreter)?;
         }
         Ok(())
     }
@@ -91,31 +102,220 @@ impl ExecuteExt for IfElseThenStatement {
 
 #[derive(Debug, Clone)]
 pub struct DoLoopStatement {
-    counter: Ident,
-    expr: Expression,
+    conditional: bool,
+    body: Vec<WordElement>,
+    step: bool,
 }
 
-impl Parse for DoLoopStatement {
-    fn parse(pair: pest::iterators::Pair<Rule>) -> Self {
-        let mut inner = pair.into_inner();
+impl DoLoopStatement {
+    #[inline]
+    pub fn new(conditional: bool, body: Vec<WordElement>, step: bool) -> Self {
         Self {
-            counter: Ident::parse(inner.next().unwrap()),
-            expr: Expression::parse(inner.next().unwrap()),
+            conditional,
+            body,
+            step,
+        }
+    }
+
+    fn run(&self, interpreter: &mut crate::ForthInterpreter) -> Result<()> {
+        loop {
+            self.body.execute(interpreter)?;
+
+            let step = if self.step {
+                match interpreter.get_unary_operand()? {
+                    Literal::Integer(step) => step,
+                    _ => return Err(ForthError::InvalidOperands),
+                }
+            } else {
+                1
+            };
+
+            let (limit, index) = interpreter.get_loop_parameters()?;
+
+            // The loop ends when the index crosses the boundary between
+            // limit - 1 and limit, in either direction.
+            let before = index.wrapping_sub(limit);
+            let after = before.wrapping_add(step);
+            if (before ^ after) < 0 && (before ^ step) < 0 {
+                return Ok(());
+            }
+
+            interpreter.return_stack.pop();
+            interpreter
+                .return_stack
+                .push(Literal::Integer(index.wrapping_add(step)));
         }
     }
 }
 
 impl ExecuteExt for DoLoopStatement {
     fn execute(&self, interpreter: &mut crate::ForthInterpreter) -> Result<()> {
-        let (start, stop) = interpreter.get_binary_operands().unwrap();
-        if let Literal::Integer(start) = start {
-            if let Literal::Integer(stop) = stop {
-                for i in start..stop {
-                    self.expr.execute(interpreter)?;
-                    interpreter.set_variable(self.counter.name(), Literal::Integer(i));
-                }
+        let (limit, start) = interpreter.get_binary_operands()?;
+        if let (Literal::Integer(limit), Literal::Integer(start)) = (&limit, &start) {
+            if self.conditional && limit == start {
+                return Ok(());
+            }
+        } else {
+            return Err(ForthError::InvalidOperands);
+        }
+
+        let base = interpreter.return_stack.length();
+        interpreter.return_stack.push(limit);
+        interpreter.return_stack.push(start);
+
+        let result = self.run(interpreter);
+        interpreter.return_stack.truncate(base);
+
+        match result {
+            Err(ForthError::Leave) => Ok(()),
+            result => result,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct BeginUntilStatement {
+    body: Vec<WordElement>,
+}
+
+impl BeginUntilStatement {
+    #[inline]
+    pub fn new(body: Vec<WordElement>) -> Self {
+        Self { body }
+    }
+}
+
+impl ExecuteExt for BeginUntilStatement {
+    fn execute(&self, interpreter: &mut crate::ForthInterpreter) -> Result<()> {
+        loop {
+            self.body.execute(interpreter)?;
+            if bool::try_from(&interpreter.get_unary_operand()?)? {
+                break;
             }
         }
         Ok(())
     }
 }
+
+#[derive(Debug, Clone)]
+pub struct BeginWhileRepeatStatement {
+    condition: Vec<WordElement>,
+    body: Vec<WordElement>,
+}
+
+impl BeginWhileRepeatStatement {
+    #[inline]
+    pub fn new(condition: Vec<WordElement>, body: Vec<WordElement>) -> Self {
+        Self { condition, body }
+    }
+}
+
+impl ExecuteExt for BeginWhileRepeatStatement {
+    fn execute(&self, interpreter: &mut crate::ForthInterpreter) -> Result<()> {
+        loop {
+            self.condition.execute(interpreter)?;
+            if !bool::try_from(&interpreter.get_unary_operand()?)? {
+                break;
+            }
+            self.body.execute(interpreter)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct BeginAgainStatement {
+    body: Vec<WordElement>,
+}
+
+impl BeginAgainStatement {
+    #[inline]
+    pub fn new(body: Vec<WordElement>) -> Self {
+        Self { body }
+    }
+}
+
+impl ExecuteExt for BeginAgainStatement {
+    fn execute(&self, interpreter: &mut crate::ForthInterpreter) -> Result<()> {
+        loop {
+            self.body.execute(interpreter)?;
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct OfClause {
+    test: Vec<WordElement>,
+    body: Vec<WordElement>,
+}
+
+impl OfClause {
+    #[inline]
+    pub fn new(test: Vec<WordElement>, body: Vec<WordElement>) -> Self {
+        Self { test, body }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct CaseStatement {
+    clauses: Vec<OfClause>,
+    default_body: Vec<WordElement>,
+}
+
+impl CaseStatement {
+    #[inline]
+    pub fn new(clauses: Vec<OfClause>, default_body: Vec<WordElement>) -> Self {
+        Self {
+            clauses,
+            default_body,
+        }
+    }
+}
+
+impl ExecuteExt for CaseStatement {
+    fn execute(&self, interpreter: &mut crate::ForthInterpreter) -> Result<()> {
+        for clause in &self.clauses {
+            clause.test.execute(interpreter)?;
+            let test = interpreter.get_unary_operand()?;
+            if *interpreter.get_last_literal()? == test {
+                interpreter.get_unary_operand()?;
+                return clause.body.execute(interpreter);
+            }
+        }
+
+        self.default_body.execute(interpreter)?;
+        interpreter.get_unary_operand()?;
+        Ok(())
+    }
+}
+
+/// The part of a defining word after `does>`, which becomes the behavior of the
+/// word most recently made by `create`.
+#[derive(Debug, Clone)]
+pub struct DoesStatement {
+    body: Vec<WordElement>,
+}
+
+impl DoesStatement {
+    #[inline]
+    pub fn new(body: Vec<WordElement>) -> Self {
+        Self { body }
+    }
+}
+
+impl ExecuteExt for DoesStatement {
+    fn execute(&self, interpreter: &mut crate::ForthInterpreter) -> Result<()> {
+        let (name, address) = interpreter
+            .last_created
+            .clone()
+            .ok_or(ForthError::DoesWithoutCreate)?;
+
+        let mut expression = Expression::new();
+        expression.push(ExpressionElement::Literal(Literal::Pointer(address)));
+
+        let mut body = vec![WordElement::Expression(expression)];
+        body.extend(self.body.iter().cloned());
+        interpreter.user_words.insert(name, Arc::new(body));
+        Ok(())
+    }
+}
@@ -9,6 +9,6 @@ pub struct Variable {
 impl Variable {
     #[inline]
     pub fn get_mut(&mut self) -> Option<&mut Literal> {
-        (&mut self.value).as_mut()
+        self.value.as_mut()
     }
 }
@@ -0,0 +1,63 @@
+use std::{
+    fmt::{self, Debug},
+    hash::{Hash, Hasher},
+    sync::Arc,
+};
+
+use crate::entities::complex::definition::WordElement;
+use crate::{search_order::SearchOrder, ExecuteExt, Result, WordFn};
+
+/// A reference to the behavior of a word, as produced by `'`. User definitions are
+/// captured by body, so redefining a name later does not change existing tokens.
+#[derive(Clone)]
+pub enum ExecutionToken {
+    Native(String, WordFn),
+    User(String, Arc<Vec<WordElement>>),
+}
+
+impl ExecutionToken {
+    /// The name of the word, as users know it.
+    pub fn name(&self) -> &str {
+        SearchOrder::name(self.key())
+    }
+
+    /// The dictionary key of the word, which qualifies its name with its word list.
+    pub fn key(&self) -> &str {
+        match self {
+            Self::Native(key, _) | Self::User(key, _) => key,
+        }
+    }
+}
+
+impl ExecuteExt for ExecutionToken {
+    fn execute(&self, interpreter: &mut crate::ForthInterpreter) -> Result<()> {
+        match self {
+            Self::Native(_, word) => word(interpreter),
+            Self::User(name, body) => interpreter.execute_body(name, body),
+        }
+    }
+}
+
+impl Debug for ExecutionToken {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "xt({})", self.name())
+    }
+}
+
+impl PartialEq for ExecutionToken {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Native(name, _), Self::Native(other_name, _)) => name == other_name,
+            (Self::User(_, body), Self::User(_, other_body)) => Arc::ptr_eq(body, other_body),
+            _ => false,
+        }
+    }
+}
+
+impl Eq for ExecutionToken {}
+
+impl Hash for ExecutionToken {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.name().hash(state);
+    }
+}
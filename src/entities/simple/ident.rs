@@ -1,6 +1,9 @@
-use crate::parser::{Parse, Rule};
+use std::fmt::{self, Display};
+
 use crate::{
     entities::simple::literal::{Literal, Pointer},
+    errors::ForthError,
+    search_order::SearchOrder,
     ExecuteExt, Result,
 };
 
@@ -10,50 +13,48 @@ pub struct Ident {
 }
 
 impl Ident {
-    pub fn name(&self) -> &str {
-        &self.name
+    #[inline]
+    pub fn new(name: impl Into<String>) -> Self {
+        Self { name: name.into() }
     }
-}
 
-impl Parse for Ident {
-    fn parse(pair: pest::iterators::Pair<Rule>) -> Self {
-        Self {
-            name: pair.as_str().to_string(),
-        }
+    pub fn name(&self) -> &str {
+        &self.name
     }
 }
 
-impl ToString for Ident {
-    fn to_string(&self) -> String {
-        self.name.clone()
+impl Display for Ident {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.name)
     }
 }
 
 impl ExecuteExt for Ident {
     fn execute(&self, interpreter: &mut crate::ForthInterpreter) -> Result<()> {
-        let name = &self.name;
-
-        if interpreter.contains_variable(name) {
-            interpreter.push(Literal::Pointer(Pointer {
-                address: interpreter.get_variable_id(name).unwrap(),
-                offset: 0,
-            }));
-        }
-
-        if interpreter.constants.contains_key(name) {
-            interpreter.push(interpreter.constants.get(name).unwrap().clone());
-        }
-
-        if interpreter.native_words.contains_key(name) {
-            let word = interpreter.native_words.get(name).unwrap();
-            word(interpreter)?;
-        }
-
-        if interpreter.user_words.contains_key(name) {
-            let word = interpreter.user_words.get(name).unwrap().clone();
-            for element in word {
-                element.execute(interpreter)?;
+        let name = self.name();
+
+        if let Some(address) = interpreter.get_variable_id(name) {
+            interpreter.push(Literal::Pointer(Pointer::new(address, 0)));
+        } else if let Some(value) = interpreter.constants.get(name) {
+            interpreter.push(value.clone());
+        } else if let Some(address) = interpreter.defers.get(name).cloned() {
+            match interpreter.get_cell(&address)? {
+                Literal::String(action) => Ident::new(action).execute(interpreter)?,
+                Literal::ExecutionToken(token) => token.execute(interpreter)?,
+                _ => {
+                    return Err(ForthError::UninitializedDefer(
+                        SearchOrder::name(name).to_string(),
+                    ))
+                }
             }
+        } else if let Some(&length) = interpreter.markers.get(name) {
+            interpreter.roll_back(length);
+        } else if interpreter.user_words.contains_key(name) {
+            interpreter.execute_user_word(name)?;
+        } else if let Some(word) = interpreter.native_words.get(name) {
+            word(interpreter)?;
+        } else {
+            return Err(ForthError::UnknownWord(SearchOrder::name(name).to_string()));
         }
 
         Ok(())
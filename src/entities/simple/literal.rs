@@ -1,15 +1,24 @@
 use cpython::{PyString, Python, ToPyObject};
 use std::{
     cmp::Ordering,
+    convert::TryFrom,
     fmt::{self, Display},
 };
 
-use crate::entities::complex::array::Array;
-use crate::parser::{Parse, Rule};
-use crate::{ExecuteExt, Result};
+use crate::entities::{complex::array::Array, simple::execution_token::ExecutionToken};
+use crate::{errors::ForthError, ExecuteExt, Result};
+
+/// The region a pointer refers to: user variables live in data space, while the
+/// interpreter's own cells (such as `>in` and the input buffer) live in system space.
+#[derive(Debug, Clone, Copy, PartialOrd, PartialEq, Eq, Hash)]
+pub enum Space {
+    Data,
+    System,
+}
 
 #[derive(Debug, Clone, PartialOrd, PartialEq, Eq, Hash)]
 pub struct Pointer {
+    pub space: Space,
     pub address: usize,
     pub offset: usize,
 }
@@ -17,7 +26,20 @@ pub struct Pointer {
 impl Pointer {
     #[inline]
     pub fn new(address: usize, offset: usize) -> Self {
-        Self { address, offset }
+        Self {
+            space: Space::Data,
+            address,
+            offset,
+        }
+    }
+
+    #[inline]
+    pub fn system(address: usize, offset: usize) -> Self {
+        Self {
+            space: Space::System,
+            address,
+            offset,
+        }
     }
 }
 
@@ -35,6 +57,8 @@ pub enum Literal {
 
     Array(ArrayType),
 
+    ExecutionToken(ExecutionToken),
+
     Unknown,
 }
 
@@ -45,31 +69,23 @@ impl ExecuteExt for Literal {
     }
 }
 
-impl Parse for Literal {
-    fn parse(pair: pest::iterators::Pair<Rule>) -> Self {
-        let inner = pair.into_inner().next().unwrap();
-        match inner.as_rule() {
-            Rule::integer => Self::Integer(inner.as_str().parse::<i64>().unwrap()),
-            Rule::string => Self::String(inner.as_str().to_string()),
-            _ => unreachable!(),
-        }
-    }
-}
-
 impl Display for Literal {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Self::Integer(i) => {
-                write!(f, "{}", i)
+                write!(f, "{i}")
             }
             Self::String(s) => {
-                write!(f, "{}", s)
+                write!(f, "{s}")
             }
             Self::Pointer(i) => {
-                write!(f, "{:?}", i)
+                write!(f, "{i:?}")
             }
             Self::Array(vec) => {
-                write!(f, "{:?}", vec)
+                write!(f, "{vec:?}")
+            }
+            Self::ExecutionToken(token) => {
+                write!(f, "{token:?}")
             }
             Self::Unknown => {
                 write!(f, "")
@@ -109,6 +125,13 @@ impl PartialEq for Literal {
                     false
                 }
             }
+            Literal::ExecutionToken(token) => {
+                if let Literal::ExecutionToken(other_token) = other {
+                    token == other_token
+                } else {
+                    false
+                }
+            }
             Literal::Unknown => {
                 matches!(other, &Literal::Unknown)
             }
@@ -147,6 +170,7 @@ impl PartialOrd for Literal {
                     None
                 }
             }
+            Self::ExecutionToken(_) => None,
             Self::Unknown => {
                 if let Literal::Unknown = other {
                     None
@@ -164,6 +188,16 @@ impl From<i64> for Literal {
     }
 }
 
+impl TryFrom<usize> for Literal {
+    type Error = ForthError;
+
+    fn try_from(value: usize) -> Result<Self> {
+        i64::try_from(value)
+            .map(Self::Integer)
+            .map_err(|_| ForthError::InvalidOperands)
+    }
+}
+
 impl From<&str> for Literal {
     fn from(value: &str) -> Self {
         Self::String(value.into())
@@ -187,12 +221,14 @@ impl ToPyObject for Literal {
     }
 }
 
-impl From<&Literal> for bool {
-    fn from(value: &Literal) -> Self {
+impl TryFrom<&Literal> for bool {
+    type Error = ForthError;
+
+    fn try_from(value: &Literal) -> Result<Self> {
         match value {
-            &Literal::Integer(e) => e == -1_i64,
-            Literal::String(_) => true,
-            _ => unreachable!(),
+            &Literal::Integer(e) => Ok(e != 0),
+            Literal::String(_) | Literal::Pointer(_) | Literal::ExecutionToken(_) => Ok(true),
+            Literal::Array(_) | Literal::Unknown => Err(ForthError::InvalidOperands),
         }
     }
 }
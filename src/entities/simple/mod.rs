@@ -1,2 +1,3 @@
+pub mod execution_token;
 pub mod ident;
 pub mod literal;
@@ -6,10 +6,46 @@ pub enum ForthError {
     StackUnderflow,
     #[error("invalid operands")]
     InvalidOperands,
+    #[error("undefined word: {0}")]
+    UnknownWord(String),
     #[error("There are no such variable")]
     VariableNotExist,
     #[error("Index out of bound")]
     IndexOutOfBound,
+    #[error("return stack underflow")]
+    ReturnStackUnderflow,
+    #[error("leave outside of a do loop")]
+    Leave,
+    #[error("exit outside of a word definition")]
+    Exit,
+    #[error("recurse outside of a word definition")]
+    RecurseOutsideDefinition,
+    #[error("interpreting a compile-only word")]
+    CompileOnly,
+    #[error("unbalanced control structure")]
+    ControlStructureMismatch,
+    #[error("missing name")]
+    MissingName,
+    #[error("return stack overflow")]
+    ReturnStackOverflow,
+    #[error("does> without a preceding create")]
+    DoesWithoutCreate,
+    #[error("not a value: {0}")]
+    NotAValue(String),
+    #[error("not a deferred word: {0}")]
+    NotADefer(String),
+    #[error("deferred word is not initialized: {0}")]
+    UninitializedDefer(String),
+    #[error("cannot include {0}: {1}")]
+    CannotInclude(String, String),
+    #[error("{0}:{1}: {2}")]
+    InFile(String, usize, Box<ForthError>),
+    #[error("search order underflow")]
+    SearchOrderUnderflow,
+    #[error("no locals frame for a local")]
+    MissingLocals,
+    #[error("division by zero")]
+    DivisionByZero,
     #[error("Other error")]
     OtherError,
 }
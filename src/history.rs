@@ -0,0 +1,26 @@
+use std::sync::Arc;
+
+use crate::entities::{
+    complex::definition::WordElement,
+    simple::literal::{Literal, Pointer},
+};
+
+/// Everything a definition replaced when it was made, so that `marker` and `forget`
+/// can roll the dictionary and data space back past it.
+#[derive(Debug, Clone)]
+pub struct HistoryEntry {
+    pub name: String,
+    pub data_length: usize,
+    pub data_region: Option<usize>,
+    pub variable: Option<usize>,
+
+    pub constant: Option<Literal>,
+    pub user_word: Option<Arc<Vec<WordElement>>>,
+    pub value: Option<Pointer>,
+    pub defer: Option<Pointer>,
+    pub marker: Option<usize>,
+    pub immediate: bool,
+
+    pub last_definition: Option<String>,
+    pub last_created: Option<(String, Pointer)>,
+}
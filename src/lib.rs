@@ -1,31 +1,49 @@
 #![warn(clippy::pedantic)]
-#![feature(hash_raw_entry)]
-
-extern crate pest;
-#[macro_use]
-extern crate pest_derive;
 
+mod compiler;
 mod entities;
 
 mod errors;
+mod history;
 pub mod parser;
+mod search_order;
 mod stack;
 mod tests;
 pub mod words;
 
-use std::{collections::HashMap, convert::TryInto};
+use std::{
+    collections::{HashMap, HashSet},
+    convert::{TryFrom, TryInto},
+    fs,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
 
+use compiler::Compiler;
 use entities::{
-    complex::{array::Array, definition::WordElement, variable::Variable},
-    simple::literal::{Literal, Pointer},
+    complex::{
+        array::Array,
+        definition::{Constant, Variable as VariableDefinition, Word, WordElement},
+        expression::{Expression, ExpressionElement},
+        variable::Variable,
+    },
+    simple::{
+        execution_token::ExecutionToken,
+        ident::Ident,
+        literal::{Literal, Pointer, Space},
+    },
 };
 use errors::ForthError::{self, InvalidOperands, StackUnderflow};
+use history::HistoryEntry;
 use stack::Stack;
 
-use pest::Parser;
-
-use parser::{ForthParser, Parse, Rule};
-use words::{IOWords, LogicWords, MathWords, OtherWords, StackWords, StandardWords};
+use parser::{Enclosure, State};
+use search_order::{SearchOrder, WordlistId, FORTH_WORDLIST};
+use words::{
+    CompileWords, ControlWords, DeferWords, DefiningWords, DictionaryWords, ExecutionWords,
+    IOWords, LogicWords, MathWords, NumberWords, OtherWords, ParsingWords, SearchOrderWords,
+    SourceWords, StackWords, StandardWords, StateWords, StringWords, StructureWords, ValueWords,
+};
 
 use console::Term;
 
@@ -49,24 +67,64 @@ trait ExecuteExt {
 
 const CELL_SIZE: i64 = 1;
 
+/// System space addresses of the interpreter's own cells.
+const TO_IN_ADDRESS: usize = 0;
+const SOURCE_ADDRESS: usize = 1;
+const BASE_ADDRESS: usize = 2;
+const STATE_ADDRESS: usize = 3;
+
+/// How deeply user words, EVALUATE and included files may nest before the call is
+/// refused. A level takes up to about 20 KiB of native stack in debug builds, so this
+/// stays well within the 8 MiB a main thread usually gets.
+const MAX_NESTING: usize = 256;
+
 pub struct ForthInterpreter {
     stack: Stack<Literal>,
+    return_stack: Stack<Literal>,
 
     terminal: console::Term,
 
     variables: Vec<Variable>,
+    data_region: Option<usize>,
     constants: HashMap<String, Literal>, // No need in Option cause constant is initialized always
 
     native_words: HashMap<String, WordFn>,
-    user_words: HashMap<String, Vec<WordElement>>,
+    user_words: HashMap<String, Arc<Vec<WordElement>>>,
+
+    immediate_words: HashSet<String>,
+    last_definition: Option<String>,
+    last_created: Option<(String, Pointer)>,
+    values: HashMap<String, Pointer>,
+    defers: HashMap<String, Pointer>,
+    markers: HashMap<String, usize>,
+    history: Vec<HistoryEntry>,
+
+    call_stack: Vec<ExecutionToken>,
+    locals: Vec<Vec<Literal>>,
+    search_order: SearchOrder,
+
+    source_files: Vec<PathBuf>,
+    included_files: HashSet<PathBuf>,
+    nesting: usize,
+
+    input: Vec<char>,
+    to_in: usize,
+
+    base: u32,
+
+    state: State,
+    compiler: Compiler,
+    enclosure: Option<Enclosure>,
 }
 
 impl MathWords for crate::ForthInterpreter {
-    fn add_ptr_offset(pointer: Pointer, offset: i64) -> Pointer {
-        Pointer {
-            offset: pointer.offset + offset as usize,
-            ..pointer
-        }
+    fn add_ptr_offset(pointer: Pointer, offset: i64) -> Result<Pointer> {
+        let offset = i64::try_from(pointer.offset)
+            .ok()
+            .and_then(|base| base.checked_add(offset))
+            .and_then(|offset| usize::try_from(offset).ok())
+            .ok_or(ForthError::IndexOutOfBound)?;
+        Ok(Pointer { offset, ..pointer })
     }
 
     fn add(&mut self) -> Result<()> {
@@ -74,17 +132,17 @@ impl MathWords for crate::ForthInterpreter {
         match a {
             Literal::Integer(a) => {
                 if let Literal::Integer(b) = b {
-                    self.push((a + b).into());
+                    self.push(a.wrapping_add(b).into());
                     return Ok(());
                 }
                 if let Literal::Pointer(b) = b {
-                    self.push(Literal::Pointer(Self::add_ptr_offset(b, a)));
+                    self.push(Literal::Pointer(Self::add_ptr_offset(b, a)?));
                     return Ok(());
                 }
             }
             Literal::Pointer(a) => {
                 if let Literal::Integer(offset) = b {
-                    self.push(Literal::Pointer(Self::add_ptr_offset(a, offset)));
+                    self.push(Literal::Pointer(Self::add_ptr_offset(a, offset)?));
                     return Ok(());
                 }
             }
@@ -97,7 +155,7 @@ impl MathWords for crate::ForthInterpreter {
         let (a, b) = self.get_binary_operands()?;
         if let Literal::Integer(a) = a {
             if let Literal::Integer(b) = b {
-                self.push(Literal::Integer(a - b));
+                self.push(Literal::Integer(a.wrapping_sub(b)));
                 return Ok(());
             }
         }
@@ -108,7 +166,7 @@ impl MathWords for crate::ForthInterpreter {
         let (a, b) = self.get_binary_operands()?;
         if let Literal::Integer(a) = a {
             if let Literal::Integer(b) = b {
-                self.push(Literal::Integer(a * b));
+                self.push(Literal::Integer(a.wrapping_mul(b)));
                 return Ok(());
             }
         }
@@ -119,7 +177,10 @@ impl MathWords for crate::ForthInterpreter {
         let (a, b) = self.get_binary_operands()?;
         if let Literal::Integer(a) = a {
             if let Literal::Integer(b) = b {
-                self.push(Literal::Integer(a / b));
+                if b == 0 {
+                    return Err(ForthError::DivisionByZero);
+                }
+                self.push(Literal::Integer(a.wrapping_div(b)));
                 return Ok(());
             }
         }
@@ -130,7 +191,10 @@ impl MathWords for crate::ForthInterpreter {
         let (a, b) = self.get_binary_operands()?;
         if let Literal::Integer(a) = a {
             if let Literal::Integer(b) = b {
-                self.push(Literal::Integer(a % b));
+                if b == 0 {
+                    return Err(ForthError::DivisionByZero);
+                }
+                self.push(Literal::Integer(a.wrapping_rem(b)));
                 return Ok(());
             }
         }
@@ -140,7 +204,7 @@ impl MathWords for crate::ForthInterpreter {
     fn negate(&mut self) -> Result<()> {
         let a = self.get_unary_operand()?;
         if let Literal::Integer(a) = a {
-            self.push(Literal::Integer(-a));
+            self.push(Literal::Integer(a.wrapping_neg()));
             return Ok(());
         }
         Err(InvalidOperands)
@@ -149,7 +213,7 @@ impl MathWords for crate::ForthInterpreter {
     fn abs(&mut self) -> Result<()> {
         let a = self.get_unary_operand()?;
         if let Literal::Integer(a) = a {
-            self.push(Literal::Integer(a.abs()));
+            self.push(Literal::Integer(a.wrapping_abs()));
             return Ok(());
         }
         Err(InvalidOperands)
@@ -236,14 +300,21 @@ impl MathWords for crate::ForthInterpreter {
 
 impl IOWords for crate::ForthInterpreter {
     fn print_top(&mut self) -> Result<()> {
-        print!("{} ", *self.stack.last().ok_or(StackUnderflow)?);
+        match self.get_last_literal()? {
+            Literal::Integer(value) => print!("{} ", parser::format_number(*value, self.base)),
+            value => print!("{value} "),
+        }
         Ok(())
     }
 
     fn emit(&mut self) -> Result<()> {
         let last = self.stack.last().ok_or(StackUnderflow)?;
         if let Literal::Integer(i) = *last {
-            print!("{}", char::from_u32(i as u32).ok_or(InvalidOperands)?);
+            print!(
+                "{}",
+                char::from_u32(i.try_into().map_err(|_| InvalidOperands)?)
+                    .ok_or(InvalidOperands)?
+            );
         }
         Ok(())
     }
@@ -263,7 +334,8 @@ impl IOWords for crate::ForthInterpreter {
     fn word(&mut self) -> Result<()> {
         let (storage, del_code) = self.get_binary_operands()?;
         if let Literal::Integer(code) = del_code {
-            let delimiter = char::from_u32(code as u32).unwrap();
+            let delimiter = char::from_u32(code.try_into().map_err(|_| InvalidOperands)?)
+                .ok_or(InvalidOperands)?;
             if let Literal::Pointer(ptr) = storage {
                 let pointer_storage = self.variables[ptr.address].get_mut().unwrap();
                 if let Literal::Array(arr) = pointer_storage {
@@ -363,58 +435,27 @@ impl StackWords for crate::ForthInterpreter {
     }
 
     fn fetch_variable(&mut self) -> Result<()> {
-        let var_index = self.get_unary_operand()?;
-        
-        if let Literal::Pointer(idx) = var_index {
-            if idx.offset == 0 {
-                self.push(
-                    self.variables[idx.address]
-                        .value
-                        .as_ref()
-                        .unwrap_or(&0_i64.into())
-                        .clone(),
-                );
-            } else {
-                if let Some(Literal::Array(arr)) = &self.variables[idx.address].value {
-                    let variable = arr
-                        .get(idx.offset)
-                        .ok_or(ForthError::IndexOutOfBound)?
-                        .clone();
-
-                    self.push(variable);
-                }
-            }
+        if let Literal::Pointer(pointer) = self.get_unary_operand()? {
+            self.push(self.get_cell(&pointer)?);
+            return Ok(());
         }
-        Ok(())
+        Err(InvalidOperands)
     }
 }
 
 impl OtherWords for crate::ForthInterpreter {
     fn store_variable(&mut self) -> Result<()> {
-        let (var_value, var_index) = self.get_binary_operands()?;
-        if let Literal::Pointer(ptr) = var_index {
-            let address = ptr.address;
-            let offset = ptr.offset;
-
-            let variable = self.variables.get_mut(address).unwrap();
-            let var_storage = variable.get_mut();
-
-            match var_storage {
-                Some(Literal::Array(arr)) => {
-                    arr.set(offset, var_value)?;
-                }
-                _ => {
-                    variable.value = Some(var_value);
-                }
-            }
+        let (value, pointer) = self.get_binary_operands()?;
+        if let Literal::Pointer(pointer) = pointer {
+            return self.set_cell(&pointer, value);
         }
-        Ok(())
+        Err(InvalidOperands)
     }
 
     fn cells(&mut self) -> Result<()> {
         let count = self.get_unary_operand()?;
         if let Literal::Integer(count) = count {
-            self.push(Literal::Integer(CELL_SIZE * count));
+            self.push(Literal::Integer(CELL_SIZE.wrapping_mul(count)));
         }
         Ok(())
     }
@@ -423,12 +464,725 @@ impl OtherWords for crate::ForthInterpreter {
         let count_of_elements = self.get_unary_operand()?;
 
         if let Literal::Integer(count_of_elements) = count_of_elements {
-            let variable = self.variables.last_mut().unwrap();
-            let array = Array::new((count_of_elements + 1).try_into().unwrap());
-            variable.value = Some(Literal::Array(array));
+            let count: usize = count_of_elements.try_into().map_err(|_| InvalidOperands)?;
+            let array = self.last_data_region()?;
+            for _ in 0..count {
+                array.grow(0.into());
+            }
+            return Ok(());
+        }
+        Err(InvalidOperands)
+    }
+
+    fn comma(&mut self) -> Result<()> {
+        let value = self.get_unary_operand()?;
+        self.last_data_region()?.grow(value);
+        Ok(())
+    }
+}
+
+impl ControlWords for crate::ForthInterpreter {
+    fn loop_index(&mut self) -> Result<()> {
+        self.push(Literal::Integer(self.get_return_integer(0)?));
+        Ok(())
+    }
+
+    fn outer_loop_index(&mut self) -> Result<()> {
+        self.push(Literal::Integer(self.get_return_integer(2)?));
+        Ok(())
+    }
+
+    fn second_outer_loop_index(&mut self) -> Result<()> {
+        self.push(Literal::Integer(self.get_return_integer(4)?));
+        Ok(())
+    }
+
+    fn leave(&mut self) -> Result<()> {
+        Err(ForthError::Leave)
+    }
+
+    fn unloop(&mut self) -> Result<()> {
+        self.get_loop_parameters()?;
+        self.return_stack.pop();
+        self.return_stack.pop();
+        Ok(())
+    }
+
+    fn recurse(&mut self) -> Result<()> {
+        self.call_stack
+            .last()
+            .ok_or(ForthError::RecurseOutsideDefinition)?
+            .clone()
+            .execute(self)
+    }
+
+    fn exit(&mut self) -> Result<()> {
+        Err(ForthError::Exit)
+    }
+}
+
+impl DefiningWords for crate::ForthInterpreter {
+    fn colon(&mut self) -> Result<()> {
+        let name = self.parse_definition_name()?;
+        self.compiler.begin_definition(name);
+        self.state = State::Compile;
+        Ok(())
+    }
+
+    fn semicolon(&mut self) -> Result<()> {
+        let (name, body) = self.compiler.end_definition()?;
+        if let Some(name) = name {
+            self.define(&name);
+            Word::new(Ident::new(name), body).execute(self)?;
+        } else {
+            self.last_definition = None;
+            self.push(Literal::ExecutionToken(ExecutionToken::User(
+                ":noname".into(),
+                Arc::new(body),
+            )));
+        }
+        self.state = State::Interpret;
+        Ok(())
+    }
+
+    fn noname(&mut self) -> Result<()> {
+        self.compiler.begin_anonymous_definition();
+        self.state = State::Compile;
+        Ok(())
+    }
+
+    fn begin_quotation(&mut self) -> Result<()> {
+        self.compiler.begin_quotation()
+    }
+
+    fn end_quotation(&mut self) -> Result<()> {
+        let body = self.compiler.end_quotation()?;
+        self.compiler
+            .compile(ExpressionElement::Literal(Literal::ExecutionToken(
+                ExecutionToken::User("[:".into(), Arc::new(body)),
+            )))
+    }
+
+    fn locals(&mut self) -> Result<()> {
+        let mut names = vec![];
+        let mut initialized = None;
+        let mut outputs = false;
+        loop {
+            let name = self
+                .parse_word_name()
+                .map_err(|_| ForthError::ControlStructureMismatch)?;
+            match name.as_str() {
+                ":}" => break,
+                _ if outputs => {}
+                "--" => outputs = true,
+                "|" if initialized.is_none() => initialized = Some(names.len()),
+                _ => names.push(name),
+            }
+        }
+
+        let initialized = initialized.unwrap_or(names.len());
+        let uninitialized = names.len() - initialized;
+        self.compiler.declare_locals(names)?;
+        self.compiler
+            .compile(ExpressionElement::EnterLocals(initialized, uninitialized))
+    }
+
+    fn variable(&mut self) -> Result<()> {
+        let name = self.parse_definition_name()?;
+        self.define(&name);
+        VariableDefinition::new(Ident::new(name)).execute(self)
+    }
+
+    fn constant(&mut self) -> Result<()> {
+        let name = self.parse_definition_name()?;
+        let value = self.get_unary_operand()?;
+        self.define(&name);
+        Constant::new(Ident::new(name), value).execute(self)
+    }
+
+    fn create(&mut self) -> Result<()> {
+        let name = self.parse_definition_name()?;
+        self.define(&name);
+        let address = self.open_data_region(vec![]);
+
+        let mut expression = Expression::new();
+        expression.push(ExpressionElement::Literal(Literal::Pointer(
+            address.clone(),
+        )));
+        Word::new(
+            Ident::new(name.as_str()),
+            vec![WordElement::Expression(expression)],
+        )
+        .execute(self)?;
+
+        self.last_created = Some((name, address));
+        Ok(())
+    }
+
+    fn does(&mut self) -> Result<()> {
+        self.compiler.compile_does()
+    }
+}
+
+impl CompileWords for crate::ForthInterpreter {
+    fn r#if(&mut self) -> Result<()> {
+        self.compiler.compile_if()
+    }
+
+    fn r#else(&mut self) -> Result<()> {
+        self.compiler.compile_else()
+    }
+
+    fn then(&mut self) -> Result<()> {
+        self.compiler.compile_then()
+    }
+
+    fn r#do(&mut self) -> Result<()> {
+        self.compiler.compile_do(false)
+    }
+
+    fn question_do(&mut self) -> Result<()> {
+        self.compiler.compile_do(true)
+    }
+
+    fn r#loop(&mut self) -> Result<()> {
+        self.compiler.compile_loop(false)
+    }
+
+    fn plus_loop(&mut self) -> Result<()> {
+        self.compiler.compile_loop(true)
+    }
+
+    fn begin(&mut self) -> Result<()> {
+        self.compiler.compile_begin()
+    }
+
+    fn until(&mut self) -> Result<()> {
+        self.compiler.compile_until()
+    }
+
+    fn again(&mut self) -> Result<()> {
+        self.compiler.compile_again()
+    }
+
+    fn r#while(&mut self) -> Result<()> {
+        self.compiler.compile_while()
+    }
+
+    fn repeat(&mut self) -> Result<()> {
+        self.compiler.compile_repeat()
+    }
+
+    fn case(&mut self) -> Result<()> {
+        self.compiler.compile_case()
+    }
+
+    fn of(&mut self) -> Result<()> {
+        self.compiler.compile_of()
+    }
+
+    fn endof(&mut self) -> Result<()> {
+        self.compiler.compile_endof()
+    }
+
+    fn endcase(&mut self) -> Result<()> {
+        self.compiler.compile_endcase()
+    }
+}
+
+impl ValueWords for crate::ForthInterpreter {
+    fn value(&mut self) -> Result<()> {
+        let name = self.parse_definition_name()?;
+        let value = self.get_unary_operand()?;
+        self.define(&name);
+        let address = self.open_data_region(vec![value]);
+
+        let mut expression = Expression::new();
+        expression.push(ExpressionElement::Literal(Literal::Pointer(
+            address.clone(),
+        )));
+        expression.push(ExpressionElement::Execute(self.native_token("@")));
+        Word::new(
+            Ident::new(name.as_str()),
+            vec![WordElement::Expression(expression)],
+        )
+        .execute(self)?;
+
+        self.values.insert(name, address);
+        Ok(())
+    }
+
+    fn to(&mut self) -> Result<()> {
+        self.update_value("!")
+    }
+
+    fn plus_to(&mut self) -> Result<()> {
+        self.update_value("+!")
+    }
+}
+
+impl ExecutionWords for crate::ForthInterpreter {
+    fn tick(&mut self) -> Result<()> {
+        let name = self.parse_defined_name()?;
+        let token = self.get_execution_token(&name)?;
+        self.push(Literal::ExecutionToken(token));
+        Ok(())
+    }
+
+    fn bracket_tick(&mut self) -> Result<()> {
+        if self.state == State::Interpret {
+            return Err(ForthError::CompileOnly);
         }
+        let name = self.parse_defined_name()?;
+        let token = self.get_execution_token(&name)?;
+        self.literal(Literal::ExecutionToken(token))
+    }
+
+    fn execute(&mut self) -> Result<()> {
+        self.get_execution_token_operand()?.execute(self)
+    }
+}
+
+impl DeferWords for crate::ForthInterpreter {
+    fn defer(&mut self) -> Result<()> {
+        let name = self.parse_definition_name()?;
+        self.define(&name);
+        let address = self.open_data_region(vec![Literal::Unknown]);
+        self.defers.insert(name, address);
         Ok(())
     }
+
+    fn is(&mut self) -> Result<()> {
+        let name = self.parse_word_name()?;
+        let name = self.find_word(&name).unwrap_or(name);
+        self.get_defer_address(&name)?;
+        self.apply_to_defer(&name, "defer!")
+    }
+
+    fn action_of(&mut self) -> Result<()> {
+        let name = self.parse_word_name()?;
+        let name = self.find_word(&name).unwrap_or(name);
+        self.get_defer_address(&name)?;
+        self.apply_to_defer(&name, "defer@")
+    }
+
+    fn defer_fetch(&mut self) -> Result<()> {
+        let name = self.get_word_name_operand()?;
+        let address = self.get_defer_address(&name)?;
+        match self.get_cell(&address)? {
+            Literal::String(action) => {
+                let action = SearchOrder::name(&action);
+                let length = Literal::try_from(action.chars().count())?;
+                let address = self.allot_data(
+                    action
+                        .chars()
+                        .map(|ch| Literal::Integer(ch as i64))
+                        .collect(),
+                );
+                self.push(Literal::Pointer(address));
+                self.push(length);
+                Ok(())
+            }
+            token @ Literal::ExecutionToken(_) => {
+                self.push(token);
+                Ok(())
+            }
+            _ => Err(ForthError::UninitializedDefer(
+                SearchOrder::name(&name).to_string(),
+            )),
+        }
+    }
+
+    fn defer_store(&mut self) -> Result<()> {
+        let name = self.get_word_name_operand()?;
+        let address = self.get_defer_address(&name)?;
+        let action = if let Some(Literal::ExecutionToken(_)) = self.stack.last() {
+            self.get_unary_operand()?
+        } else {
+            let action = self.get_string_operand()?;
+            let action = self
+                .find_word(&action)
+                .ok_or(ForthError::UnknownWord(action))?;
+            Literal::String(action)
+        };
+        self.set_cell(&address, action)
+    }
+}
+
+impl DictionaryWords for crate::ForthInterpreter {
+    fn marker(&mut self) -> Result<()> {
+        let name = self.parse_definition_name()?;
+        let length = self.history.len();
+        self.define(&name);
+        self.markers.insert(name, length);
+        Ok(())
+    }
+
+    fn forget(&mut self) -> Result<()> {
+        let name = self.parse_defined_name()?;
+        let length = self
+            .history
+            .iter()
+            .rposition(|entry| entry.name == name)
+            .ok_or_else(|| ForthError::UnknownWord(SearchOrder::name(&name).to_string()))?;
+        self.roll_back(length);
+        Ok(())
+    }
+}
+
+impl StateWords for crate::ForthInterpreter {
+    fn immediate(&mut self) -> Result<()> {
+        let name = self
+            .last_definition
+            .clone()
+            .ok_or(ForthError::MissingName)?;
+        self.immediate_words.insert(name);
+        Ok(())
+    }
+
+    fn state(&mut self) -> Result<()> {
+        self.push(Literal::Pointer(Pointer::system(STATE_ADDRESS, 0)));
+        Ok(())
+    }
+
+    fn left_bracket(&mut self) -> Result<()> {
+        self.state = State::Interpret;
+        Ok(())
+    }
+
+    fn right_bracket(&mut self) -> Result<()> {
+        if !self.compiler.is_compiling() {
+            return Err(ForthError::CompileOnly);
+        }
+        self.state = State::Compile;
+        Ok(())
+    }
+
+    fn literal(&mut self) -> Result<()> {
+        if self.state == State::Interpret {
+            return Err(ForthError::CompileOnly);
+        }
+        let value = self.get_unary_operand()?;
+        self.literal(value)
+    }
+
+    fn postpone(&mut self) -> Result<()> {
+        if self.state == State::Interpret {
+            return Err(ForthError::CompileOnly);
+        }
+        let name = self.parse_defined_name()?;
+        let ident = Ident::new(name.as_str());
+        if self.immediate_words.contains(&name) {
+            self.compiler.compile(ExpressionElement::Ident(ident))
+        } else {
+            self.compiler.compile(ExpressionElement::Compile(ident))
+        }
+    }
+}
+
+impl SourceWords for crate::ForthInterpreter {
+    fn evaluate(&mut self) -> Result<()> {
+        let text = self.get_string_operand()?;
+        self.interpret_text(&text).map_err(|(_, error)| error)
+    }
+
+    fn include(&mut self) -> Result<()> {
+        let path = self.parse_word_name()?;
+        self.include_path(Path::new(&path), false)
+    }
+
+    fn included(&mut self) -> Result<()> {
+        let path = self.get_string_operand()?;
+        self.include_path(Path::new(&path), false)
+    }
+
+    fn require(&mut self) -> Result<()> {
+        let path = self.parse_word_name()?;
+        self.include_path(Path::new(&path), true)
+    }
+
+    fn required(&mut self) -> Result<()> {
+        let path = self.get_string_operand()?;
+        self.include_path(Path::new(&path), true)
+    }
+}
+
+impl ParsingWords for crate::ForthInterpreter {
+    fn paren(&mut self) -> Result<()> {
+        self.skip_comment();
+        Ok(())
+    }
+
+    fn backslash(&mut self) -> Result<()> {
+        self.to_in = self.input.len();
+        Ok(())
+    }
+
+    fn parse_name(&mut self) -> Result<()> {
+        let (start, end) =
+            parser::parse_name(&self.input, &mut self.to_in).unwrap_or((self.to_in, self.to_in));
+        self.push_source_slice(start, end)
+    }
+
+    fn parse(&mut self) -> Result<()> {
+        let delimiter = match self.get_unary_operand()? {
+            Literal::Integer(code) => char::from_u32(code.try_into().map_err(|_| InvalidOperands)?)
+                .ok_or(InvalidOperands)?,
+            _ => return Err(InvalidOperands),
+        };
+        let (start, end, _) = parser::parse_until(&self.input, &mut self.to_in, delimiter);
+        self.push_source_slice(start, end)
+    }
+
+    fn to_in(&mut self) -> Result<()> {
+        self.push(Literal::Pointer(Pointer::system(TO_IN_ADDRESS, 0)));
+        Ok(())
+    }
+
+    fn source(&mut self) -> Result<()> {
+        self.push_source_slice(0, self.input.len())
+    }
+
+    fn char(&mut self) -> Result<()> {
+        let code = self.parse_char()?;
+        self.push(code);
+        Ok(())
+    }
+
+    fn bracket_char(&mut self) -> Result<()> {
+        if self.state == State::Interpret {
+            return Err(ForthError::CompileOnly);
+        }
+        let code = self.parse_char()?;
+        self.literal(code)
+    }
+}
+
+impl StringWords for crate::ForthInterpreter {
+    fn dot_quote(&mut self) -> Result<()> {
+        let text = self.parse_text('"');
+        match self.state {
+            State::Interpret => {
+                print!("{}", text.iter().collect::<String>());
+                Ok(())
+            }
+            State::Compile => {
+                self.string_literal(text)?;
+                let r#type = self.native_token("type");
+                self.compiler.compile(ExpressionElement::Execute(r#type))
+            }
+        }
+    }
+
+    fn dot_paren(&mut self) -> Result<()> {
+        print!("{}", self.parse_text(')').iter().collect::<String>());
+        Ok(())
+    }
+
+    fn s_quote(&mut self) -> Result<()> {
+        let text = self.parse_text('"');
+        self.string_literal(text)
+    }
+
+    fn s_backslash_quote(&mut self) -> Result<()> {
+        let (text, _) = parser::parse_escaped(&self.input, &mut self.to_in)?;
+        self.string_literal(text)
+    }
+
+    fn c_quote(&mut self) -> Result<()> {
+        let text = self.parse_text('"');
+        let mut counted = vec![Literal::try_from(text.len())?];
+        counted.extend(text.into_iter().map(|ch| Literal::Integer(ch as i64)));
+        let address = self.allot_data(counted);
+        self.literal(Literal::Pointer(address))
+    }
+
+    fn r#type(&mut self) -> Result<()> {
+        let text = self.get_string_operand()?;
+        print!("{text}");
+        Ok(())
+    }
+
+    fn count(&mut self) -> Result<()> {
+        if let Literal::Pointer(address) = self.get_unary_operand()? {
+            let length = self.get_cell(&address)?;
+            self.push(Literal::Pointer(Self::add_ptr_offset(address, 1)?));
+            self.push(length);
+            return Ok(());
+        }
+        Err(InvalidOperands)
+    }
+}
+
+impl NumberWords for crate::ForthInterpreter {
+    fn base(&mut self) -> Result<()> {
+        self.push(Literal::Pointer(Pointer::system(BASE_ADDRESS, 0)));
+        Ok(())
+    }
+
+    fn hex(&mut self) -> Result<()> {
+        self.base = 16;
+        Ok(())
+    }
+
+    fn decimal(&mut self) -> Result<()> {
+        self.base = 10;
+        Ok(())
+    }
+
+    fn binary(&mut self) -> Result<()> {
+        self.base = 2;
+        Ok(())
+    }
+}
+
+impl StructureWords for crate::ForthInterpreter {
+    fn begin_structure(&mut self) -> Result<()> {
+        let name = self.parse_definition_name()?;
+        self.define(&name);
+        let address = self.open_data_region(vec![0.into()]);
+
+        let mut expression = Expression::new();
+        expression.push(ExpressionElement::Literal(Literal::Pointer(
+            address.clone(),
+        )));
+        expression.push(ExpressionElement::Execute(self.native_token("@")));
+        Word::new(
+            Ident::new(name.as_str()),
+            vec![WordElement::Expression(expression)],
+        )
+        .execute(self)?;
+
+        self.push(Literal::Pointer(address));
+        self.push(0.into());
+        Ok(())
+    }
+
+    fn end_structure(&mut self) -> Result<()> {
+        let (address, size) = self.get_binary_operands()?;
+        match (address, size) {
+            (Literal::Pointer(address), size @ Literal::Integer(_)) => {
+                self.set_cell(&address, size)
+            }
+            _ => Err(InvalidOperands),
+        }
+    }
+
+    fn plus_field(&mut self) -> Result<()> {
+        let name = self.parse_definition_name()?;
+        let (Literal::Integer(offset), Literal::Integer(size)) = self.get_binary_operands()? else {
+            return Err(InvalidOperands);
+        };
+        self.define(&name);
+
+        let mut expression = Expression::new();
+        expression.push(ExpressionElement::Literal(offset.into()));
+        expression.push(ExpressionElement::Execute(self.native_token("+")));
+        Word::new(
+            Ident::new(name.as_str()),
+            vec![WordElement::Expression(expression)],
+        )
+        .execute(self)?;
+
+        self.push((offset + size).into());
+        Ok(())
+    }
+
+    fn field(&mut self) -> Result<()> {
+        self.push(CELL_SIZE.into());
+        self.plus_field()
+    }
+}
+
+impl SearchOrderWords for crate::ForthInterpreter {
+    fn forth_wordlist(&mut self) -> Result<()> {
+        self.push(Literal::try_from(FORTH_WORDLIST)?);
+        Ok(())
+    }
+
+    fn wordlist(&mut self) -> Result<()> {
+        let wordlist = self.search_order.create_wordlist();
+        self.push(Literal::try_from(wordlist)?);
+        Ok(())
+    }
+
+    fn get_current(&mut self) -> Result<()> {
+        self.push(Literal::try_from(self.search_order.current())?);
+        Ok(())
+    }
+
+    fn set_current(&mut self) -> Result<()> {
+        let wordlist = self.get_wordlist_operand()?;
+        self.search_order.set_current(wordlist);
+        Ok(())
+    }
+
+    fn get_order(&mut self) -> Result<()> {
+        let order = self.search_order.order().to_vec();
+        for &wordlist in order.iter().rev() {
+            self.push(Literal::try_from(wordlist)?);
+        }
+        self.push(Literal::try_from(order.len())?);
+        Ok(())
+    }
+
+    fn set_order(&mut self) -> Result<()> {
+        let count: usize = match self.get_unary_operand()? {
+            Literal::Integer(-1) => {
+                self.search_order.only();
+                return Ok(());
+            }
+            Literal::Integer(count) => count.try_into().map_err(|_| InvalidOperands)?,
+            _ => return Err(InvalidOperands),
+        };
+        let order = (0..count)
+            .map(|_| self.get_wordlist_operand())
+            .collect::<Result<_>>()?;
+        self.search_order.set_order(order);
+        Ok(())
+    }
+
+    fn also(&mut self) -> Result<()> {
+        self.search_order.also()
+    }
+
+    fn only(&mut self) -> Result<()> {
+        self.search_order.only();
+        Ok(())
+    }
+
+    fn previous(&mut self) -> Result<()> {
+        self.search_order.previous()
+    }
+
+    fn forth(&mut self) -> Result<()> {
+        self.search_order.replace_first(FORTH_WORDLIST)
+    }
+
+    fn definitions(&mut self) -> Result<()> {
+        self.search_order.definitions()
+    }
+
+    fn vocabulary(&mut self) -> Result<()> {
+        let name = self.parse_definition_name()?;
+        self.define(&name);
+        let wordlist = self.search_order.create_wordlist();
+
+        // get-order swap drop <wordlist> swap set-order
+        let mut expression = Expression::new();
+        for word in ["get-order", "swap", "drop"] {
+            expression.push(ExpressionElement::Execute(self.native_token(word)));
+        }
+        expression.push(ExpressionElement::Literal(Literal::try_from(wordlist)?));
+        for word in ["swap", "set-order"] {
+            expression.push(ExpressionElement::Execute(self.native_token(word)));
+        }
+        Word::new(
+            Ident::new(name.as_str()),
+            vec![WordElement::Expression(expression)],
+        )
+        .execute(self)
+    }
 }
 
 impl StandardWords for ForthInterpreter {}
@@ -441,16 +1195,44 @@ impl Default for ForthInterpreter {
 
 impl ForthInterpreter {
     #[inline]
+    #[must_use]
     pub fn new() -> Self {
         Self {
             stack: Stack::new(),
+            return_stack: Stack::new(),
             variables: Vec::new(),
+            data_region: None,
             constants: HashMap::new(),
 
             terminal: Term::stdout(),
 
             native_words: <Self as StandardWords>::get_words(),
-            user_words: HashMap::<String, Vec<WordElement>>::new(),
+            user_words: HashMap::<String, Arc<Vec<WordElement>>>::new(),
+
+            immediate_words: <Self as StandardWords>::get_immediate_words(),
+            last_definition: None,
+            last_created: None,
+            values: HashMap::new(),
+            defers: HashMap::new(),
+            markers: HashMap::new(),
+            history: Vec::new(),
+
+            call_stack: Vec::new(),
+            locals: Vec::new(),
+            search_order: SearchOrder::new(),
+
+            source_files: Vec::new(),
+            included_files: HashSet::new(),
+            nesting: 0,
+
+            input: Vec::new(),
+            to_in: 0,
+
+            base: 10,
+
+            state: State::Interpret,
+            compiler: Compiler::new(),
+            enclosure: None,
         }
     }
 
@@ -464,67 +1246,667 @@ impl ForthInterpreter {
         Ok((a, b))
     }
 
+    /// Returns the top of the data stack without removing it.
+    ///
+    /// # Errors
+    ///
+    /// Returns `StackUnderflow` if the stack is empty.
     #[inline]
     pub fn get_last_literal(&self) -> Result<&Literal> {
         self.stack.last().ok_or(StackUnderflow)
     }
 
     #[inline]
+    #[must_use]
     pub fn get_stack_dump(&self) -> &Stack<Literal> {
         &self.stack
     }
 
     #[inline]
+    #[must_use]
     pub fn get_vars_dump(&self) -> &Vec<Variable> {
         &self.variables
     }
 
     #[inline]
+    #[must_use]
     pub fn get_consts_dump(&self) -> &HashMap<String, Literal> {
         &self.constants
     }
 
     #[inline]
+    #[must_use]
     pub fn get_native_words_dump(&self) -> &HashMap<String, WordFn> {
         &self.native_words
     }
 
     #[inline]
-    pub fn get_user_words_dump(&self) -> &HashMap<String, Vec<WordElement>> {
+    #[must_use]
+    pub fn get_user_words_dump(&self) -> &HashMap<String, Arc<Vec<WordElement>>> {
         &self.user_words
     }
 
-    fn set_variable(&mut self, name: &str, value: Literal) {
-        let variable = self.variables.iter_mut().find(|var| var.name == name);
-        match variable {
-            None => self.variables.push(Variable {
-                name: name.to_string(),
-                value: Some(value),
-            }),
-            Some(e) => {
-                e.value = Some(value);
-            }
+    fn execute_user_word(&mut self, name: &str) -> Result<()> {
+        let word = self
+            .user_words
+            .get(name)
+            .ok_or_else(|| ForthError::UnknownWord(SearchOrder::name(name).to_string()))?
+            .clone();
+        self.execute_body(name, &word)
+    }
+
+    /// Runs the body of a user definition; `recurse` inside it runs the same body again.
+    fn execute_body(&mut self, name: &str, word: &Arc<Vec<WordElement>>) -> Result<()> {
+        self.enter_nesting()?;
+        let locals = self.locals.len();
+        self.call_stack
+            .push(ExecutionToken::User(name.to_string(), word.clone()));
+        let result = word.execute(self);
+        self.call_stack.pop();
+        self.locals.truncate(locals);
+        self.nesting -= 1;
+
+        match result {
+            Err(ForthError::Exit) => Ok(()),
+            result => result,
+        }
+    }
+
+    fn get_return_integer(&self, depth: usize) -> Result<i64> {
+        let length = self.return_stack.length();
+        if depth >= length {
+            return Err(ForthError::ReturnStackUnderflow);
+        }
+        match self.return_stack.get(length - 1 - depth) {
+            Literal::Integer(value) => Ok(*value),
+            _ => Err(InvalidOperands),
         }
     }
 
-    fn contains_variable(&self, name: &str) -> bool {
-        !matches!(self.variables.iter().find(|var| var.name == name), None)
+    fn get_loop_parameters(&self) -> Result<(i64, i64)> {
+        Ok((self.get_return_integer(1)?, self.get_return_integer(0)?))
     }
 
     fn get_variable_id(&self, name: &str) -> Option<usize> {
         self.variables.iter().position(|var| var.name == name)
     }
 
+    fn is_defined(&self, name: &str) -> bool {
+        self.get_variable_id(name).is_some()
+            || self.constants.contains_key(name)
+            || self.defers.contains_key(name)
+            || self.markers.contains_key(name)
+            || self.user_words.contains_key(name)
+            || self.native_words.contains_key(name)
+    }
+
+    /// Returns the key of the first definition of `name` in the search order.
+    fn find_word(&self, name: &str) -> Option<String> {
+        self.search_order
+            .keys(name)
+            .find(|key| self.is_defined(key))
+    }
+
+    /// Returns the execution token of the word `name` as currently defined.
+    fn get_execution_token(&self, name: &str) -> Result<ExecutionToken> {
+        let late_bound = || {
+            let mut expression = Expression::new();
+            expression.push(ExpressionElement::Ident(Ident::new(name)));
+            ExecutionToken::User(
+                name.to_string(),
+                Arc::new(vec![WordElement::Expression(expression)]),
+            )
+        };
+
+        if self.get_variable_id(name).is_some()
+            || self.constants.contains_key(name)
+            || self.defers.contains_key(name)
+            || self.markers.contains_key(name)
+        {
+            Ok(late_bound())
+        } else if let Some(body) = self.user_words.get(name) {
+            Ok(ExecutionToken::User(name.to_string(), body.clone()))
+        } else if let Some(&word) = self.native_words.get(name) {
+            Ok(ExecutionToken::Native(name.to_string(), word))
+        } else {
+            Err(ForthError::UnknownWord(SearchOrder::name(name).to_string()))
+        }
+    }
+
+    /// Returns a token for the standard word `name`, unaffected by user definitions
+    /// of the same name.
+    fn native_token(&self, name: &str) -> ExecutionToken {
+        ExecutionToken::Native(name.to_string(), self.native_words[name])
+    }
+
+    fn get_defer_address(&self, name: &str) -> Result<Pointer> {
+        self.defers
+            .get(name)
+            .cloned()
+            .ok_or_else(|| ForthError::NotADefer(SearchOrder::name(name).to_string()))
+    }
+
+    fn get_wordlist_operand(&mut self) -> Result<WordlistId> {
+        match self.get_unary_operand()? {
+            Literal::Integer(wordlist) => self.search_order.check(wordlist),
+            _ => Err(InvalidOperands),
+        }
+    }
+
+    fn get_execution_token_operand(&mut self) -> Result<ExecutionToken> {
+        match self.get_unary_operand()? {
+            Literal::ExecutionToken(token) => Ok(token),
+            _ => Err(InvalidOperands),
+        }
+    }
+
+    /// Pops a word given either by its execution token or as an (addr len) name, and
+    /// returns its name.
+    fn get_word_name_operand(&mut self) -> Result<String> {
+        if let Some(Literal::ExecutionToken(_)) = self.stack.last() {
+            Ok(self.get_execution_token_operand()?.key().to_string())
+        } else {
+            let name = self.get_string_operand()?;
+            Ok(self.find_word(&name).unwrap_or(name))
+        }
+    }
+
+    fn get_cell(&self, pointer: &Pointer) -> Result<Literal> {
+        match pointer.space {
+            Space::Data => {
+                let variable = self
+                    .variables
+                    .get(pointer.address)
+                    .ok_or(ForthError::VariableNotExist)?;
+                match &variable.value {
+                    Some(Literal::Array(arr)) => arr
+                        .get(pointer.offset)
+                        .cloned()
+                        .ok_or(ForthError::IndexOutOfBound),
+                    _ if pointer.offset != 0 => Err(ForthError::IndexOutOfBound),
+                    value => Ok(value.clone().unwrap_or_else(|| 0.into())),
+                }
+            }
+            Space::System => match pointer.address {
+                TO_IN_ADDRESS => Literal::try_from(self.to_in),
+                BASE_ADDRESS => Ok(Literal::Integer(i64::from(self.base))),
+                STATE_ADDRESS => Ok(Literal::Integer(match self.state {
+                    State::Interpret => 0,
+                    State::Compile => -1,
+                })),
+                SOURCE_ADDRESS => self
+                    .input
+                    .get(pointer.offset)
+                    .map(|&ch| Literal::Integer(ch as i64))
+                    .ok_or(ForthError::IndexOutOfBound),
+                _ => Err(ForthError::VariableNotExist),
+            },
+        }
+    }
+
+    fn set_cell(&mut self, pointer: &Pointer, value: Literal) -> Result<()> {
+        match pointer.space {
+            Space::Data => {
+                let variable = self
+                    .variables
+                    .get_mut(pointer.address)
+                    .ok_or(ForthError::VariableNotExist)?;
+                match variable.get_mut() {
+                    Some(Literal::Array(arr)) => arr.set(pointer.offset, value),
+                    _ if pointer.offset != 0 => Err(ForthError::IndexOutOfBound),
+                    _ => {
+                        variable.value = Some(value);
+                        Ok(())
+                    }
+                }
+            }
+            Space::System => match (pointer.address, value) {
+                (TO_IN_ADDRESS, Literal::Integer(position)) => {
+                    self.to_in = position.try_into().map_err(|_| InvalidOperands)?;
+                    Ok(())
+                }
+                (BASE_ADDRESS, Literal::Integer(base)) if (2..=36).contains(&base) => {
+                    self.base = base.try_into().map_err(|_| InvalidOperands)?;
+                    Ok(())
+                }
+                _ => Err(InvalidOperands),
+            },
+        }
+    }
+
+    /// Returns the data region opened by the most recent defining word as an array,
+    /// converting a plain variable cell into the first element of one.
+    fn last_data_region(&mut self) -> Result<&mut Array> {
+        let index = self.data_region.ok_or(ForthError::VariableNotExist)?;
+        let variable = &mut self.variables[index];
+        if !matches!(variable.value, Some(Literal::Array(_))) {
+            let value = variable.value.take().unwrap_or_else(|| 0.into());
+            variable.value = Some(Literal::Array(Array::from(vec![value])));
+        }
+        match variable.get_mut() {
+            Some(Literal::Array(array)) => Ok(array),
+            _ => unreachable!(),
+        }
+    }
+
+    /// Places `cells` in a fresh, unnamed region of data space and returns its address.
+    fn allot_data(&mut self, cells: Vec<Literal>) -> Pointer {
+        self.variables.push(Variable {
+            name: String::new(),
+            value: Some(Literal::Array(Array::from(cells))),
+        });
+        Pointer::new(self.variables.len() - 1, 0)
+    }
+
+    /// Places `cells` in a fresh region of data space that `,` and `allot` extend
+    /// until the next defining word opens another one.
+    fn open_data_region(&mut self, cells: Vec<Literal>) -> Pointer {
+        let address = self.allot_data(cells);
+        self.data_region = Some(address.address);
+        address
+    }
+
+    /// Stores `text` in data space and pushes or compiles its (addr len) pair.
+    fn string_literal(&mut self, text: Vec<char>) -> Result<()> {
+        let length = Literal::try_from(text.len())?;
+        let address = self.allot_data(
+            text.into_iter()
+                .map(|ch| Literal::Integer(ch as i64))
+                .collect(),
+        );
+        self.literal(Literal::Pointer(address))?;
+        self.literal(length)
+    }
+
+    /// Pops an (addr len) pair and reads the characters it refers to.
+    fn get_string_operand(&mut self) -> Result<String> {
+        let (address, length) = self.get_binary_operands()?;
+        match (address, length) {
+            (Literal::Pointer(address), Literal::Integer(length)) => (0..length)
+                .map(|offset| {
+                    match self.get_cell(&Self::add_ptr_offset(address.clone(), offset)?)? {
+                        Literal::Integer(code) => {
+                            char::from_u32(code.try_into().map_err(|_| InvalidOperands)?)
+                                .ok_or(InvalidOperands)
+                        }
+                        _ => Err(InvalidOperands),
+                    }
+                })
+                .collect(),
+            _ => Err(InvalidOperands),
+        }
+    }
+
+    /// Interprets `text` line by line as the input source, then returns to the
+    /// input source that was being interpreted before. Errors come with the number
+    /// of the line they occurred on.
+    fn interpret_text(&mut self, text: &str) -> std::result::Result<(), (usize, ForthError)> {
+        self.enter_nesting().map_err(|error| (0, error))?;
+        let input = std::mem::take(&mut self.input);
+        let to_in = self.to_in;
+        let enclosure = self.enclosure.take();
+
+        let result = text.lines().enumerate().try_for_each(|(index, line)| {
+            self.input = line.chars().collect();
+            self.to_in = 0;
+            self.interpret().map_err(|error| (index + 1, error))
+        });
+
+        self.input = input;
+        self.to_in = to_in;
+        self.enclosure = enclosure;
+        self.nesting -= 1;
+        result
+    }
+
+    /// Counts one more level of nested words or source text, refusing to go deeper
+    /// than `MAX_NESTING`.
+    fn enter_nesting(&mut self) -> Result<()> {
+        if self.nesting >= MAX_NESTING {
+            return Err(ForthError::ReturnStackOverflow);
+        }
+        self.nesting += 1;
+        Ok(())
+    }
+
+    /// Interprets the source file at `path`, which is relative to the file being
+    /// included, if any. With `once`, a file that was already included is skipped.
+    /// A file may not include itself, directly or through other files.
+    fn include_path(&mut self, path: &Path, once: bool) -> Result<()> {
+        let path = match self.source_files.last().and_then(|file| file.parent()) {
+            Some(directory) => directory.join(path),
+            None => path.to_path_buf(),
+        };
+        let cannot_include = |error: std::io::Error| {
+            ForthError::CannotInclude(path.display().to_string(), error.to_string())
+        };
+
+        let path = path.canonicalize().map_err(cannot_include)?;
+        if once && self.included_files.contains(&path) {
+            return Ok(());
+        }
+        if self.source_files.contains(&path) {
+            return Err(ForthError::CannotInclude(
+                path.display().to_string(),
+                String::from("the file is already being included"),
+            ));
+        }
+        let text = fs::read_to_string(&path).map_err(cannot_include)?;
+        self.included_files.insert(path.clone());
+
+        self.source_files.push(path);
+        let result = self.interpret_text(&text);
+        let path = self.source_files.pop().unwrap();
+
+        result.map_err(|(line, error)| match error {
+            error @ ForthError::InFile(..) => error,
+            error => ForthError::InFile(path.display().to_string(), line, Box::new(error)),
+        })
+    }
+
+    fn parse_text(&mut self, delimiter: char) -> Vec<char> {
+        let (start, end, _) = parser::parse_until(&self.input, &mut self.to_in, delimiter);
+        self.input[start..end].to_vec()
+    }
+
+    fn push_source_slice(&mut self, start: usize, end: usize) -> Result<()> {
+        self.push(Literal::Pointer(Pointer::system(SOURCE_ADDRESS, start)));
+        self.push(Literal::try_from(end - start)?);
+        Ok(())
+    }
+
+    fn parse_word_name(&mut self) -> Result<String> {
+        let (start, end) =
+            parser::parse_name(&self.input, &mut self.to_in).ok_or(ForthError::MissingName)?;
+        Ok(self.input[start..end].iter().collect())
+    }
+
+    /// Parses a name and returns the key a definition of it gets in the current word list.
+    fn parse_definition_name(&mut self) -> Result<String> {
+        let name = self.parse_word_name()?;
+        Ok(self.search_order.definition_key(&name))
+    }
+
+    /// Parses a name and returns the key of the word it finds in the search order.
+    fn parse_defined_name(&mut self) -> Result<String> {
+        let name = self.parse_word_name()?;
+        self.find_word(&name).ok_or(ForthError::UnknownWord(name))
+    }
+
+    /// Records `name` as the most recent definition, so that it replaces any earlier
+    /// word of the same name whatever its kind, along with its immediate flag.
+    fn define(&mut self, name: &str) {
+        let variable = self.get_variable_id(name);
+        if let Some(index) = variable {
+            self.variables[index].name.clear();
+        }
+
+        self.history.push(HistoryEntry {
+            name: name.to_string(),
+            data_length: self.variables.len(),
+            data_region: self.data_region,
+            variable,
+            constant: self.constants.remove(name),
+            user_word: self.user_words.remove(name),
+            value: self.values.remove(name),
+            defer: self.defers.remove(name),
+            marker: self.markers.remove(name),
+            immediate: self.immediate_words.remove(name),
+            last_definition: self.last_definition.take(),
+            last_created: self.last_created.take(),
+        });
+        self.last_definition = Some(name.to_string());
+    }
+
+    /// Undoes every definition from the `length`-th one on, restoring whatever they
+    /// replaced and releasing the data space allocated since.
+    fn roll_back(&mut self, length: usize) {
+        while self.history.len() > length {
+            let entry = self.history.pop().unwrap();
+            let name = entry.name;
+
+            self.variables.truncate(entry.data_length);
+            self.data_region = entry.data_region;
+            if let Some(index) = entry.variable {
+                self.variables[index].name.clone_from(&name);
+            }
+            match entry.constant {
+                Some(constant) => self.constants.insert(name.clone(), constant),
+                None => self.constants.remove(&name),
+            };
+            match entry.user_word {
+                Some(word) => self.user_words.insert(name.clone(), word),
+                None => self.user_words.remove(&name),
+            };
+            match entry.value {
+                Some(address) => self.values.insert(name.clone(), address),
+                None => self.values.remove(&name),
+            };
+            match entry.defer {
+                Some(address) => self.defers.insert(name.clone(), address),
+                None => self.defers.remove(&name),
+            };
+            match entry.marker {
+                Some(length) => self.markers.insert(name.clone(), length),
+                None => self.markers.remove(&name),
+            };
+            if entry.immediate {
+                self.immediate_words.insert(name);
+            } else {
+                self.immediate_words.remove(&name);
+            }
+
+            self.last_definition = entry.last_definition;
+            self.last_created = entry.last_created;
+        }
+    }
+
+    /// Moves the top `initialized` stack items into a new locals frame, followed by
+    /// `uninitialized` zeroed locals.
+    fn enter_locals(&mut self, initialized: usize, uninitialized: usize) -> Result<()> {
+        let length = self.stack.length();
+        if length < initialized {
+            return Err(StackUnderflow);
+        }
+        let mut frame: Vec<Literal> = (length - initialized..length)
+            .map(|index| self.stack.get(index).clone())
+            .collect();
+        self.stack.truncate(length - initialized);
+        frame.resize(initialized + uninitialized, 0.into());
+        self.locals.push(frame);
+        Ok(())
+    }
+
+    fn get_local(&mut self, index: usize) -> Result<&mut Literal> {
+        self.locals
+            .last_mut()
+            .and_then(|frame| frame.get_mut(index))
+            .ok_or(ForthError::MissingLocals)
+    }
+
+    /// Parses the name of a value or local and applies the store word `operation` to it,
+    /// right away or when the definition being compiled runs.
+    fn update_value(&mut self, operation: &str) -> Result<()> {
+        let name = self.parse_word_name()?;
+        if self.state == State::Compile {
+            if let Some(index) = self.compiler.find_local(&name) {
+                if operation == "+!" {
+                    let add = self.native_token("+");
+                    self.compiler.compile(ExpressionElement::Local(index))?;
+                    self.compiler.compile(ExpressionElement::Execute(add))?;
+                }
+                return self.compiler.compile(ExpressionElement::ToLocal(index));
+            }
+        }
+        let name = self.find_word(&name).unwrap_or(name);
+        let address = self
+            .values
+            .get(&name)
+            .cloned()
+            .ok_or_else(|| ForthError::NotAValue(SearchOrder::name(&name).to_string()))?;
+
+        let operation = self.native_token(operation);
+        match self.state {
+            State::Interpret => {
+                self.push(Literal::Pointer(address));
+                operation.execute(self)
+            }
+            State::Compile => {
+                self.compiler
+                    .compile(ExpressionElement::Literal(Literal::Pointer(address)))?;
+                self.compiler.compile(ExpressionElement::Execute(operation))
+            }
+        }
+    }
+
+    /// Passes the name of the deferred word `name` to `operation`, right away or when
+    /// the definition being compiled runs.
+    fn apply_to_defer(&mut self, name: &str, operation: &str) -> Result<()> {
+        self.string_literal(name.chars().collect())?;
+        let operation = self.native_token(operation);
+        match self.state {
+            State::Interpret => operation.execute(self),
+            State::Compile => self.compiler.compile(ExpressionElement::Execute(operation)),
+        }
+    }
+
+    fn parse_char(&mut self) -> Result<Literal> {
+        let (start, _) =
+            parser::parse_name(&self.input, &mut self.to_in).ok_or(ForthError::MissingName)?;
+        Ok(Literal::Integer(self.input[start] as i64))
+    }
+
+    fn skip_comment(&mut self) {
+        let (_, _, closed) = parser::parse_until(&self.input, &mut self.to_in, ')');
+        if !closed {
+            self.enclosure = Some(Enclosure::Comment);
+        }
+    }
+
+    fn parse_string(&mut self, mut text: String) -> Result<()> {
+        let (start, end, closed) = parser::parse_until(&self.input, &mut self.to_in, '"');
+        text.extend(&self.input[start..end]);
+        if closed {
+            text.push('"');
+            self.literal(Literal::String(text))
+        } else {
+            text.push('\n');
+            self.enclosure = Some(Enclosure::String(text));
+            Ok(())
+        }
+    }
+
+    fn literal(&mut self, value: Literal) -> Result<()> {
+        match self.state {
+            State::Interpret => {
+                self.push(value);
+                Ok(())
+            }
+            State::Compile => self.compiler.compile(ExpressionElement::Literal(value)),
+        }
+    }
+
+    fn interpret_token(&mut self, name: &str) -> Result<()> {
+        if let Some(number) = parser::parse_number(name, self.base) {
+            return self.literal(number.into());
+        }
+
+        if self.state == State::Compile {
+            if let Some(index) = self.compiler.find_local(name) {
+                return self.compiler.compile(ExpressionElement::Local(index));
+            }
+        }
+
+        let key = match self.find_word(name) {
+            Some(key) => key,
+            None if self.state == State::Compile
+                && self.compiler.definition_name()
+                    == Some(self.search_order.definition_key(name).as_str()) =>
+            {
+                self.search_order.definition_key(name)
+            }
+            None => return Err(ForthError::UnknownWord(name.to_string())),
+        };
+
+        if self.state == State::Compile && !self.immediate_words.contains(&key) {
+            return self
+                .compiler
+                .compile(ExpressionElement::Ident(Ident::new(key)));
+        }
+
+        Ident::new(key).execute(self)
+    }
+
+    fn interpret(&mut self) -> Result<()> {
+        match self.enclosure.take() {
+            Some(Enclosure::Comment) => self.skip_comment(),
+            Some(Enclosure::String(text)) => self.parse_string(text)?,
+            None => {}
+        }
+
+        while let Some((start, end)) = parser::parse_name(&self.input, &mut self.to_in) {
+            if self.input[start] == '"' {
+                self.to_in = start + 1;
+                self.parse_string(String::from("\""))?;
+                continue;
+            }
+
+            let name: String = self.input[start..end].iter().collect();
+            self.interpret_token(&name)?;
+        }
+
+        Ok(())
+    }
+
     #[inline]
+    #[must_use]
+    pub fn get_state(&self) -> State {
+        self.state
+    }
+
+    /// Feeds one line of source text to the interpreter. Definitions, comments and strings
+    /// left open at the end of the line stay pending until a later call closes them.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of the first word that fails; anything left open is dropped.
     pub fn execute_line(&mut self, line: &str) -> Result<()> {
-        let line_pair = ForthParser::parse(Rule::line, line).unwrap();
-        let line = entities::Line::parse(line_pair.peek().unwrap());
+        self.input = line.chars().collect();
+        self.to_in = 0;
 
-        line.execute(self)?;
+        let result = self.interpret();
+        if result.is_err() {
+            self.abandon();
+        }
+        result
+    }
 
-        Ok(())
+    /// Interprets the source file at `path`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CannotInclude` if the file cannot be read, and otherwise wraps the first
+    /// error in `InFile` with the file and line it occurred on.
+    pub fn include_file(&mut self, path: impl AsRef<Path>) -> Result<()> {
+        let result = self.include_path(path.as_ref(), false);
+        if result.is_err() {
+            self.abandon();
+        }
+        result
     }
 
+    /// Drops whatever was left open by source text that failed.
+    fn abandon(&mut self) {
+        self.compiler.reset();
+        self.state = State::Interpret;
+        self.enclosure = None;
+    }
+
+    /// Feeds `text` to the interpreter line by line.
+    ///
+    /// # Errors
+    ///
+    /// Stops at the first line that fails and returns its error.
     #[inline]
     pub fn execute(&mut self, text: &str) -> Result<()> {
         for line in text.lines() {
@@ -557,7 +1939,7 @@ mod interpreter_tests {
 
     #[test]
     fn test_parsing() {
-        let mut forth: ForthInterpreter = ForthInterpreter::new();
+        let _forth: ForthInterpreter = ForthInterpreter::new();
         //forth.execute_line("a b +")
     }
 
@@ -576,7 +1958,7 @@ mod interpreter_tests {
                 assert_eq!(ptr.address, 0);
                 assert_eq!(ptr.offset, 0);
             }
-            _ => assert!(false),
+            _ => unreachable!(),
         }
     }
 }
@@ -1,58 +1,259 @@
-use pest::Parser;
+use std::convert::TryFrom;
 
-#[derive(Parser)]
-#[grammar = "parser/forth.pest"]
-pub struct ForthParser;
+use crate::{errors::ForthError::InvalidOperands, Result};
 
-pub trait Parse {
-    fn parse(pair: pest::iterators::Pair<Rule>) -> Self;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum State {
+    Interpret,
+    Compile,
+}
+
+/// Constructs which may be opened on one line and closed on another.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Enclosure {
+    Comment,
+    String(String),
+}
+
+/// Skips leading whitespace and returns the bounds of the next whitespace-delimited name,
+/// leaving `position` just past the delimiter that ended it.
+pub fn parse_name(text: &[char], position: &mut usize) -> Option<(usize, usize)> {
+    let mut start = (*position).min(text.len());
+    while start < text.len() && text[start].is_whitespace() {
+        start += 1;
+    }
+    if start == text.len() {
+        *position = start;
+        return None;
+    }
+
+    let mut end = start;
+    while end < text.len() && !text[end].is_whitespace() {
+        end += 1;
+    }
+
+    *position = (end + 1).min(text.len());
+    Some((start, end))
+}
+
+/// Returns the bounds of the text up to `delimiter` and whether the delimiter was found,
+/// leaving `position` just past it.
+pub fn parse_until(text: &[char], position: &mut usize, delimiter: char) -> (usize, usize, bool) {
+    let start = (*position).min(text.len());
+    if let Some(length) = text[start..].iter().position(|&ch| ch == delimiter) {
+        *position = start + length + 1;
+        (start, start + length, true)
+    } else {
+        *position = text.len();
+        (start, text.len(), false)
+    }
+}
+
+/// Like [`parse_until`] with a `"` delimiter, but translates the escape sequences of
+/// `s\"` along the way.
+///
+/// # Errors
+///
+/// Returns `InvalidOperands` for an unknown escape or a `\x` not followed by two hex digits.
+pub fn parse_escaped(text: &[char], position: &mut usize) -> Result<(Vec<char>, bool)> {
+    let mut result = vec![];
+    let mut chars = text[(*position).min(text.len())..].iter();
+
+    let closed = loop {
+        let ch = match chars.next() {
+            Some('"') => break true,
+            Some('\\') => match chars.next() {
+                Some('a') => '\u{7}',
+                Some('b') => '\u{8}',
+                Some('e') => '\u{1b}',
+                Some('f') => '\u{c}',
+                Some('l' | 'n') => '\n',
+                Some('m') => {
+                    result.push('\r');
+                    '\n'
+                }
+                Some('q' | '"') => '"',
+                Some('r') => '\r',
+                Some('t') => '\t',
+                Some('v') => '\u{b}',
+                Some('z') => '\0',
+                Some('\\') => '\\',
+                Some('x') => {
+                    let digits: String = chars.by_ref().take(2).collect();
+                    let code = u8::from_str_radix(&digits, 16).map_err(|_| InvalidOperands)?;
+                    char::from(code)
+                }
+                _ => return Err(InvalidOperands),
+            },
+            Some(&ch) => ch,
+            None => break false,
+        };
+        result.push(ch);
+    };
+
+    *position = text.len() - chars.as_slice().len();
+    Ok((result, closed))
+}
+
+/// Converts `token` to a number in `base`, honoring the Forth-2012 `#`, `$` and `%`
+/// prefixes and `'c'` character literals.
+#[must_use]
+pub fn parse_number(token: &str, base: u32) -> Option<i64> {
+    let chars: Vec<char> = token.chars().collect();
+    if let ['\'', ch, '\''] = chars[..] {
+        return Some(ch as i64);
+    }
+
+    let (base, digits) = match chars.split_first() {
+        Some(('#', rest)) => (10, rest),
+        Some(('$', rest)) => (16, rest),
+        Some(('%', rest)) => (2, rest),
+        _ => (base, &chars[..]),
+    };
+    let (negative, digits) = match digits.split_first() {
+        Some(('-', rest)) => (true, rest),
+        Some(('+', rest)) => (false, rest),
+        _ => (false, digits),
+    };
+    if digits.is_empty() {
+        return None;
+    }
+
+    let mut value: i64 = 0;
+    for ch in digits {
+        let digit = ch.to_digit(base)?;
+        value = value
+            .checked_mul(i64::from(base))?
+            .checked_add(i64::from(digit))?;
+    }
+    Some(if negative { -value } else { value })
+}
+
+/// Renders `value` in `base` using upper-case digits.
+///
+/// # Panics
+///
+/// Panics if `base` is not between 2 and 36.
+#[must_use]
+pub fn format_number(value: i64, base: u32) -> String {
+    let mut magnitude = value.unsigned_abs();
+    let mut digits = vec![];
+    loop {
+        let digit = u32::try_from(magnitude % u64::from(base)).unwrap();
+        digits.push(
+            std::char::from_digit(digit, base)
+                .unwrap()
+                .to_ascii_uppercase(),
+        );
+        magnitude /= u64::from(base);
+        if magnitude == 0 {
+            break;
+        }
+    }
+    if value < 0 {
+        digits.push('-');
+    }
+    digits.iter().rev().collect()
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    fn chars(text: &str) -> Vec<char> {
+        text.chars().collect()
+    }
+
+    fn names(text: &str) -> Vec<String> {
+        let text = chars(text);
+        let mut position = 0;
+        let mut names = vec![];
+        while let Some((start, end)) = parse_name(&text, &mut position) {
+            names.push(text[start..end].iter().collect());
+        }
+        names
+    }
+
     #[test]
-    fn test_parse_comment() {
-        ForthParser::parse(Rule::COMMENT, "(hi)").unwrap();
-        ForthParser::parse(Rule::COMMENT, "(h\ni)").unwrap_err();
-        ForthParser::parse(Rule::COMMENT, "(hi").unwrap_err();
+    fn test_parse_name() {
+        assert_eq!(names("1 2 3 * +"), vec!["1", "2", "3", "*", "+"]);
+        assert_eq!(
+            names("  : buzz?\t5 mod ;  "),
+            vec![":", "buzz?", "5", "mod", ";"]
+        );
+        assert_eq!(
+            names("2dup .s ?dup >r c@ 1+"),
+            vec!["2dup", ".s", "?dup", ">r", "c@", "1+"]
+        );
+        assert!(names("").is_empty());
+        assert!(names("   \t ").is_empty());
     }
 
     #[test]
-    fn test_parse_integer() {
-        ForthParser::parse(Rule::integer, "123").unwrap();
-        ForthParser::parse(Rule::integer, "-123").unwrap();
+    fn test_parse_name_position() {
+        let text = chars("foo bar");
+        let mut position = 0;
 
-        ForthParser::parse(Rule::integer, "--123").unwrap_err();
-        ForthParser::parse(Rule::integer, "hello").unwrap_err();
+        assert_eq!(parse_name(&text, &mut position), Some((0, 3)));
+        assert_eq!(position, 4);
+        assert_eq!(parse_name(&text, &mut position), Some((4, 7)));
+        assert_eq!(position, 7);
+        assert_eq!(parse_name(&text, &mut position), None);
     }
 
     #[test]
-    fn test_parse_string() {
-        ForthParser::parse(Rule::string, r#""hello""#).unwrap();
-        ForthParser::parse(Rule::string, r#""""#).unwrap();
+    fn test_parse_until() {
+        let text = chars("( a comment ) 1 2");
+        let mut position = 2;
 
-        ForthParser::parse(Rule::string, r#"123"#).unwrap_err();
-        ForthParser::parse(Rule::string, r#"'hello'"#).unwrap_err();
+        assert_eq!(parse_until(&text, &mut position, ')'), (2, 12, true));
+        assert_eq!(position, 13);
+
+        assert_eq!(parse_until(&text, &mut position, ')'), (13, 17, false));
+        assert_eq!(position, 17);
     }
 
     #[test]
-    fn test_parse_word() {
-        ForthParser::parse(Rule::ident, "hello").unwrap();
-        ForthParser::parse(Rule::ident, "hello_world").unwrap();
-        ForthParser::parse(Rule::ident, "second_stack_2").unwrap();
+    fn test_parse_escaped() {
+        let text = chars(r#"a\tb\"c\x41\\" rest"#);
+        let mut position = 0;
+
+        let (parsed, closed) = parse_escaped(&text, &mut position).unwrap();
+        assert_eq!(parsed, chars("a\tb\"cA\\"));
+        assert!(closed);
+        assert_eq!(position, 14);
 
-        ForthParser::parse(Rule::user_ident, "8hello").unwrap_err();
-        ForthParser::parse(Rule::ident, "*").unwrap();
+        let mut position = 0;
+        assert!(parse_escaped(&chars(r#"\xZZ""#), &mut position).is_err());
+        assert!(parse_escaped(&chars(r#"\y""#), &mut position).is_err());
     }
 
     #[test]
-    fn test_parse_expression() {
-        ForthParser::parse(Rule::expression, "1 2 3 * +").unwrap();
+    fn test_parse_number() {
+        assert_eq!(parse_number("42", 10), Some(42));
+        assert_eq!(parse_number("-42", 10), Some(-42));
+        assert_eq!(parse_number("ff", 16), Some(255));
+        assert_eq!(parse_number("FF", 16), Some(255));
+        assert_eq!(parse_number("ff", 10), None);
+        assert_eq!(parse_number("$ff", 10), Some(255));
+        assert_eq!(parse_number("$-ff", 10), Some(-255));
+        assert_eq!(parse_number("#10", 16), Some(10));
+        assert_eq!(parse_number("%1010", 10), Some(10));
+        assert_eq!(parse_number("'a'", 10), Some(97));
+        assert_eq!(parse_number("'λ'", 10), Some(955));
+        assert_eq!(parse_number("+5", 10), Some(5));
+        assert_eq!(parse_number("-", 10), None);
+        assert_eq!(parse_number("$", 10), None);
+        assert_eq!(parse_number("1+", 10), None);
+        assert_eq!(parse_number("99999999999999999999", 10), None);
+    }
 
-        ForthParser::parse(Rule::expression, "2 5 *").unwrap();
-        ForthParser::parse(Rule::expression, "foo").unwrap();
-        ForthParser::parse(Rule::expression, "1 2 3 dup").unwrap();
+    #[test]
+    fn test_format_number() {
+        assert_eq!(format_number(0, 10), "0");
+        assert_eq!(format_number(-123, 10), "-123");
+        assert_eq!(format_number(255, 16), "FF");
+        assert_eq!(format_number(-10, 2), "-1010");
+        assert_eq!(format_number(i64::MIN, 16), "-8000000000000000");
     }
 }
@@ -0,0 +1,135 @@
+use std::convert::TryInto;
+
+use crate::{errors::ForthError, Result};
+
+pub type WordlistId = usize;
+
+/// The word list holding the standard words and everything defined before any
+/// other word list is made current.
+pub const FORTH_WORDLIST: WordlistId = 0;
+
+/// The word lists searched when a name is looked up, first one first, and the one
+/// new definitions are added to.
+///
+/// Definitions are kept in the interpreter's dictionary under a key qualifying
+/// their name with the word list; the key of a FORTH-WORDLIST definition is its
+/// plain name.
+#[derive(Debug, Clone)]
+pub struct SearchOrder {
+    order: Vec<WordlistId>,
+    current: WordlistId,
+    wordlists: usize,
+}
+
+impl Default for SearchOrder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SearchOrder {
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            order: vec![FORTH_WORDLIST],
+            current: FORTH_WORDLIST,
+            wordlists: 1,
+        }
+    }
+
+    pub fn key(name: &str, wordlist: WordlistId) -> String {
+        if wordlist == FORTH_WORDLIST {
+            name.to_string()
+        } else {
+            format!("{name}\u{0}{wordlist}")
+        }
+    }
+
+    /// Returns the name `key` was made from, which is what users see of the definition.
+    pub fn name(key: &str) -> &str {
+        key.split_once('\u{0}').map_or(key, |(name, _)| name)
+    }
+
+    /// Returns the key a definition of `name` gets in the current word list.
+    pub fn definition_key(&self, name: &str) -> String {
+        Self::key(name, self.current)
+    }
+
+    /// Returns the keys `name` may be defined under, in search order.
+    pub fn keys<'a>(&'a self, name: &'a str) -> impl Iterator<Item = String> + 'a {
+        self.order
+            .iter()
+            .map(move |&wordlist| Self::key(name, wordlist))
+    }
+
+    pub fn create_wordlist(&mut self) -> WordlistId {
+        self.wordlists += 1;
+        self.wordlists - 1
+    }
+
+    pub fn check(&self, wordlist: i64) -> Result<WordlistId> {
+        let wordlist: WordlistId = wordlist
+            .try_into()
+            .map_err(|_| ForthError::InvalidOperands)?;
+        if wordlist < self.wordlists {
+            Ok(wordlist)
+        } else {
+            Err(ForthError::InvalidOperands)
+        }
+    }
+
+    #[inline]
+    pub fn current(&self) -> WordlistId {
+        self.current
+    }
+
+    #[inline]
+    pub fn set_current(&mut self, wordlist: WordlistId) {
+        self.current = wordlist;
+    }
+
+    #[inline]
+    pub fn order(&self) -> &[WordlistId] {
+        &self.order
+    }
+
+    #[inline]
+    pub fn set_order(&mut self, order: Vec<WordlistId>) {
+        self.order = order;
+    }
+
+    /// Resets the search order to FORTH-WORDLIST alone.
+    pub fn only(&mut self) {
+        self.order = vec![FORTH_WORDLIST];
+    }
+
+    pub fn also(&mut self) -> Result<()> {
+        let first = *self.order.first().ok_or(ForthError::SearchOrderUnderflow)?;
+        self.order.insert(0, first);
+        Ok(())
+    }
+
+    pub fn previous(&mut self) -> Result<()> {
+        if self.order.is_empty() {
+            return Err(ForthError::SearchOrderUnderflow);
+        }
+        self.order.remove(0);
+        Ok(())
+    }
+
+    /// Replaces the first word list of the search order.
+    pub fn replace_first(&mut self, wordlist: WordlistId) -> Result<()> {
+        let first = self
+            .order
+            .first_mut()
+            .ok_or(ForthError::SearchOrderUnderflow)?;
+        *first = wordlist;
+        Ok(())
+    }
+
+    /// Makes the first word list of the search order the current one.
+    pub fn definitions(&mut self) -> Result<()> {
+        self.current = *self.order.first().ok_or(ForthError::SearchOrderUnderflow)?;
+        Ok(())
+    }
+}
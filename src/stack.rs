@@ -37,8 +37,9 @@ impl<T: Clone> Stack<T> {
         self.stack.len()
     }
 
-    pub(crate) fn swap(&mut self, a: usize, b: usize) {
-        self.stack.swap(a, b)
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.stack.is_empty()
     }
 
     pub(crate) fn get(&self, a: usize) -> &T {
@@ -49,8 +50,8 @@ impl<T: Clone> Stack<T> {
         self.stack.remove(a)
     }
 
-    pub(crate) fn is_empty(&self) -> bool {
-        self.stack.is_empty()
+    pub(crate) fn truncate(&mut self, length: usize) {
+        self.stack.truncate(length);
     }
 }
 
@@ -0,0 +1,108 @@
+#[cfg(test)]
+mod anonymous_tests {
+    use crate::{errors::ForthError, parser::State, ForthInterpreter, Literal, Stack};
+
+    #[test]
+    fn noname() {
+        let mut interpreter = ForthInterpreter::new();
+
+        interpreter.execute(":noname 2 * ;").unwrap();
+        assert!(matches!(
+            interpreter.get_last_literal().unwrap(),
+            Literal::ExecutionToken(_)
+        ));
+        assert_eq!(interpreter.get_state(), State::Interpret);
+
+        interpreter.execute("21 swap execute").unwrap();
+        assert_eq!(interpreter.get_stack_dump(), &Stack::from(vec![42.into()]));
+    }
+
+    #[test]
+    fn noname_does_not_add_a_word() {
+        let mut interpreter = ForthInterpreter::new();
+
+        interpreter.execute(":noname 1 ; drop").unwrap();
+        assert!(interpreter.get_user_words_dump().is_empty());
+    }
+
+    #[test]
+    fn immediate_after_noname() {
+        let mut interpreter = ForthInterpreter::new();
+
+        interpreter.execute(": foo 1 ; :noname 2 ; drop").unwrap();
+        assert!(matches!(
+            interpreter.execute("immediate"),
+            Err(ForthError::MissingName)
+        ));
+        interpreter.execute(": bar foo ; bar").unwrap();
+        assert_eq!(interpreter.get_stack_dump(), &Stack::from(vec![1.into()]));
+    }
+
+    #[test]
+    fn noname_with_defer_and_recurse() {
+        let mut interpreter = ForthInterpreter::new();
+
+        interpreter
+            .execute("defer factorial :noname dup 1 > if dup 1 - recurse * then ; is factorial")
+            .unwrap();
+        interpreter.execute("5 factorial").unwrap();
+        assert_eq!(interpreter.get_stack_dump(), &Stack::from(vec![120.into()]));
+    }
+
+    #[test]
+    fn quotation() {
+        let mut interpreter = ForthInterpreter::new();
+
+        interpreter
+            .execute(": twice ( n xt -- n' ) swap over execute swap execute ;")
+            .unwrap();
+        interpreter
+            .execute(": add-ten [: 5 + ;] twice ; 1 add-ten")
+            .unwrap();
+        assert_eq!(interpreter.get_stack_dump(), &Stack::from(vec![11.into()]));
+    }
+
+    #[test]
+    fn nested_quotations() {
+        let mut interpreter = ForthInterpreter::new();
+
+        interpreter
+            .execute(": outer [: [: 3 ;] execute 4 + ;] execute ; outer")
+            .unwrap();
+        assert_eq!(interpreter.get_stack_dump(), &Stack::from(vec![7.into()]));
+    }
+
+    #[test]
+    fn quotation_inside_statements() {
+        let mut interpreter = ForthInterpreter::new();
+
+        interpreter
+            .execute(": pick-op ( flag -- xt ) if [: + ;] else [: - ;] then ;")
+            .unwrap();
+        interpreter
+            .execute("10 3 -1 pick-op execute 10 3 0 pick-op execute")
+            .unwrap();
+        assert_eq!(
+            interpreter.get_stack_dump(),
+            &Stack::from(vec![13.into(), 7.into()])
+        );
+    }
+
+    #[test]
+    fn quotation_misuse() {
+        let mut interpreter = ForthInterpreter::new();
+
+        assert!(matches!(
+            interpreter.execute("[: 1 ;]"),
+            Err(ForthError::CompileOnly)
+        ));
+        assert!(matches!(
+            interpreter.execute(": broken [: 1 ;"),
+            Err(ForthError::ControlStructureMismatch)
+        ));
+        assert!(matches!(
+            interpreter.execute(": broken 1 ;]"),
+            Err(ForthError::ControlStructureMismatch)
+        ));
+    }
+}
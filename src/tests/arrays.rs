@@ -16,7 +16,7 @@ mod array_tests {
             Some(Literal::Array(arr)) => {
                 assert_eq!(arr.capacity(), 4);
             }
-            _ => assert!(false),
+            _ => unreachable!(),
         }
     }
 
@@ -43,7 +43,7 @@ mod array_tests {
                 assert_eq!(arr[2], Literal::Integer(30));
                 assert_eq!(arr[3], Literal::Integer(50));
             }
-            _ => assert!(false),
+            _ => unreachable!(),
         }
         assert!(interpreter.get_stack_dump().is_empty());
 
@@ -0,0 +1,89 @@
+#[cfg(test)]
+mod case_tests {
+    use crate::{errors::ForthError, ForthInterpreter, Literal, Stack};
+
+    #[test]
+    fn matching_clause_drops_selector() {
+        let mut interpreter = ForthInterpreter::new();
+
+        interpreter
+            .execute(": digit case 1 of 10 endof 2 of 20 endof 3 of 30 endof endcase ;")
+            .unwrap();
+
+        for i in 1..=3 {
+            interpreter.execute(&format!("{i} digit")).unwrap();
+            assert_eq!(
+                interpreter.get_unary_operand().unwrap(),
+                Literal::Integer(i * 10)
+            );
+            interpreter.get_unary_operand().unwrap_err();
+        }
+    }
+
+    #[test]
+    fn default_branch() {
+        let mut interpreter = ForthInterpreter::new();
+
+        interpreter
+            .execute(": classify case 0 of 100 endof dup 1000 + swap endcase ;")
+            .unwrap();
+
+        interpreter.execute("0 classify").unwrap();
+        assert_eq!(interpreter.get_stack_dump(), &Stack::from(vec![100.into()]));
+        interpreter.clear_state();
+
+        interpreter
+            .execute(": classify case 0 of 100 endof dup 1000 + swap endcase ;")
+            .unwrap();
+        interpreter.execute("7 classify").unwrap();
+        assert_eq!(
+            interpreter.get_stack_dump(),
+            &Stack::from(vec![1007.into()])
+        );
+    }
+
+    #[test]
+    fn no_match_without_default() {
+        let mut interpreter = ForthInterpreter::new();
+
+        interpreter
+            .execute(": pick_one case 1 of 10 endof endcase 5 ;")
+            .unwrap();
+
+        interpreter.execute("9 pick_one").unwrap();
+        assert_eq!(interpreter.get_stack_dump(), &Stack::from(vec![5.into()]));
+        interpreter.execute("drop").unwrap();
+
+        assert!(matches!(
+            interpreter.execute("pick_one"),
+            Err(ForthError::StackUnderflow)
+        ));
+    }
+
+    #[test]
+    fn computed_tests_and_nesting() {
+        let mut interpreter = ForthInterpreter::new();
+
+        interpreter
+            .execute(": parity case 2 2 * of 4 endof dup 2 mod 0 = if 0 else 1 then swap endcase ;")
+            .unwrap();
+
+        interpreter.execute("4 parity 6 parity 7 parity").unwrap();
+        assert_eq!(
+            interpreter.get_stack_dump(),
+            &Stack::from(vec![4.into(), 0.into(), 1.into()])
+        );
+        interpreter.clear_state();
+
+        interpreter
+            .execute(
+                ": nested case 1 of 2 0 do i case 0 of 5 endof 6 swap endcase loop endof endcase ;",
+            )
+            .unwrap();
+        interpreter.execute("1 nested").unwrap();
+        assert_eq!(
+            interpreter.get_stack_dump(),
+            &Stack::from(vec![5.into(), 6.into()])
+        );
+    }
+}
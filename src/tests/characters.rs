@@ -0,0 +1,49 @@
+#[cfg(test)]
+mod characters_tests {
+    use crate::{errors::ForthError, ForthInterpreter, Stack};
+
+    #[test]
+    fn char() {
+        let mut interpreter = ForthInterpreter::new();
+
+        interpreter
+            .execute("char A char hello char λ char 😀")
+            .unwrap();
+        assert_eq!(
+            interpreter.get_stack_dump(),
+            &Stack::from(vec![65.into(), 104.into(), 955.into(), 0x1F600.into()])
+        );
+
+        assert!(matches!(
+            interpreter.execute("char"),
+            Err(ForthError::MissingName)
+        ));
+    }
+
+    #[test]
+    fn bracket_char() {
+        let mut interpreter = ForthInterpreter::new();
+
+        interpreter
+            .execute(": space? [char] ß = ; char ß space? 32 space?")
+            .unwrap();
+        assert_eq!(
+            interpreter.get_stack_dump(),
+            &Stack::from(vec![(-1).into(), 0.into()])
+        );
+    }
+
+    #[test]
+    fn bracket_char_is_compile_only() {
+        let mut interpreter = ForthInterpreter::new();
+
+        assert!(matches!(
+            interpreter.execute("[char] a"),
+            Err(ForthError::CompileOnly)
+        ));
+        assert!(matches!(
+            interpreter.execute(": broken [char]"),
+            Err(ForthError::MissingName)
+        ));
+    }
+}
@@ -0,0 +1,69 @@
+#[cfg(test)]
+mod comments_tests {
+    use crate::{parser::State, ForthInterpreter, Stack};
+
+    #[test]
+    fn line_comment() {
+        let mut interpreter = ForthInterpreter::new();
+
+        interpreter
+            .execute("\\ a file header\n1 2 \\ + this is ignored\n3")
+            .unwrap();
+        assert_eq!(
+            interpreter.get_stack_dump(),
+            &Stack::from(vec![1.into(), 2.into(), 3.into()])
+        );
+    }
+
+    #[test]
+    fn stack_effect_comments() {
+        let mut interpreter = ForthInterpreter::new();
+
+        interpreter
+            .execute(": square ( n -- n*n ) dup * ;  ( and after )\n4 square")
+            .unwrap();
+        assert_eq!(interpreter.get_stack_dump(), &Stack::from(vec![16.into()]));
+    }
+
+    #[test]
+    fn comments_inside_statements() {
+        let mut interpreter = ForthInterpreter::new();
+
+        interpreter
+            .execute(
+                ": sign ( n -- -1|0|1 )
+                    dup 0 < if ( negative ) drop -1 \\ done
+                    else 0 > if 1 ( positive ) else 0 then
+                    then ;
+                -5 sign 0 sign 7 sign",
+            )
+            .unwrap();
+        assert_eq!(
+            interpreter.get_stack_dump(),
+            &Stack::from(vec![(-1).into(), 0.into(), 1.into()])
+        );
+    }
+
+    #[test]
+    fn paren_comment_across_calls() {
+        let mut interpreter = ForthInterpreter::new();
+
+        interpreter.execute(": add ( a b").unwrap();
+        interpreter.execute("   -- sum ; ignored").unwrap();
+        assert_eq!(interpreter.get_state(), State::Compile);
+
+        interpreter.execute(") + ;").unwrap();
+        assert_eq!(interpreter.get_state(), State::Interpret);
+
+        interpreter.execute("2 3 add").unwrap();
+        assert_eq!(interpreter.get_stack_dump(), &Stack::from(vec![5.into()]));
+    }
+
+    #[test]
+    fn backslash_inside_paren_comment() {
+        let mut interpreter = ForthInterpreter::new();
+
+        interpreter.execute("( \\ still a comment ) 1").unwrap();
+        assert_eq!(interpreter.get_stack_dump(), &Stack::from(vec![1.into()]));
+    }
+}
@@ -0,0 +1,125 @@
+#[cfg(test)]
+mod compile_state_tests {
+    use crate::{errors::ForthError, parser::State, ForthInterpreter, Literal, Stack};
+
+    #[test]
+    fn definition_across_lines() {
+        let mut interpreter = ForthInterpreter::new();
+
+        interpreter.execute_line(": add_ten").unwrap();
+        assert_eq!(interpreter.get_state(), State::Compile);
+
+        interpreter.execute_line("  10").unwrap();
+        interpreter.execute_line("  + ;").unwrap();
+        assert_eq!(interpreter.get_state(), State::Interpret);
+
+        interpreter.execute_line("5 add_ten").unwrap();
+        assert_eq!(
+            interpreter.get_unary_operand().unwrap(),
+            Literal::Integer(15)
+        );
+    }
+
+    #[test]
+    fn statements_across_lines() {
+        let mut interpreter = ForthInterpreter::new();
+
+        interpreter
+            .execute(
+                ": count_down\n  begin\n    dup\n    1 -\n    dup 0 =\n  until\n;\n3 count_down",
+            )
+            .unwrap();
+        assert_eq!(
+            interpreter.get_stack_dump(),
+            &Stack::from(vec![3.into(), 2.into(), 1.into(), 0.into()])
+        );
+    }
+
+    #[test]
+    fn code_before_definition_runs_immediately() {
+        let mut interpreter = ForthInterpreter::new();
+
+        interpreter.execute_line("1 2 : three").unwrap();
+        assert_eq!(
+            interpreter.get_stack_dump(),
+            &Stack::from(vec![1.into(), 2.into()])
+        );
+
+        interpreter.execute_line("3 ; three").unwrap();
+        assert_eq!(
+            interpreter.get_stack_dump(),
+            &Stack::from(vec![1.into(), 2.into(), 3.into()])
+        );
+    }
+
+    #[test]
+    fn several_definitions_on_one_line() {
+        let mut interpreter = ForthInterpreter::new();
+
+        interpreter.execute_line(": a 1 ; : b 2 ; a b").unwrap();
+        assert_eq!(
+            interpreter.get_stack_dump(),
+            &Stack::from(vec![1.into(), 2.into()])
+        );
+    }
+
+    #[test]
+    fn comment_across_lines() {
+        let mut interpreter = ForthInterpreter::new();
+
+        interpreter.execute_line("1 ( this comment").unwrap();
+        interpreter
+            .execute_line("    keeps going ; : here")
+            .unwrap();
+        assert_eq!(interpreter.get_state(), State::Interpret);
+
+        interpreter.execute_line(") 2").unwrap();
+        assert_eq!(
+            interpreter.get_stack_dump(),
+            &Stack::from(vec![1.into(), 2.into()])
+        );
+    }
+
+    #[test]
+    fn string_across_lines() {
+        let mut interpreter = ForthInterpreter::new();
+
+        interpreter.execute_line("\"first").unwrap();
+        interpreter.execute_line("second\"").unwrap();
+        assert_eq!(
+            interpreter.get_unary_operand().unwrap(),
+            Literal::String("\"first\nsecond\"".into())
+        );
+    }
+
+    #[test]
+    fn missing_name() {
+        let mut interpreter = ForthInterpreter::new();
+
+        assert!(matches!(
+            interpreter.execute_line(":"),
+            Err(ForthError::MissingName)
+        ));
+        assert_eq!(interpreter.get_state(), State::Interpret);
+
+        interpreter.execute_line("1").unwrap();
+        assert_eq!(interpreter.get_stack_dump(), &Stack::from(vec![1.into()]));
+    }
+
+    #[test]
+    fn error_abandons_definition() {
+        let mut interpreter = ForthInterpreter::new();
+
+        assert!(matches!(
+            interpreter.execute_line(": broken 1 then ;"),
+            Err(ForthError::ControlStructureMismatch)
+        ));
+        assert_eq!(interpreter.get_state(), State::Interpret);
+        assert!(!interpreter.get_user_words_dump().contains_key("broken"));
+
+        assert!(matches!(
+            interpreter.execute_line("if"),
+            Err(ForthError::CompileOnly)
+        ));
+    }
+}
@@ -0,0 +1,140 @@
+#[cfg(test)]
+mod counted_loops_tests {
+    use crate::{errors::ForthError, ForthInterpreter, Stack};
+
+    #[test]
+    fn index_is_current_on_first_iteration() {
+        let mut interpreter = ForthInterpreter::new();
+
+        interpreter.execute(": indices 3 0 do i loop ;").unwrap();
+        interpreter.execute("indices").unwrap();
+        assert_eq!(
+            interpreter.get_stack_dump(),
+            &Stack::from(vec![0.into(), 1.into(), 2.into()])
+        );
+    }
+
+    #[test]
+    fn nested_indices() {
+        let mut interpreter = ForthInterpreter::new();
+
+        interpreter
+            .execute(": pairs 2 0 do 2 0 do j 10 * i + loop loop ;")
+            .unwrap();
+        interpreter.execute("pairs").unwrap();
+        assert_eq!(
+            interpreter.get_stack_dump(),
+            &Stack::from(vec![0.into(), 1.into(), 10.into(), 11.into()])
+        );
+        interpreter.clear_state();
+
+        interpreter
+            .execute(": triples 2 1 do 3 2 do 4 3 do k j i loop loop loop ;")
+            .unwrap();
+        interpreter.execute("triples").unwrap();
+        assert_eq!(
+            interpreter.get_stack_dump(),
+            &Stack::from(vec![1.into(), 2.into(), 3.into()])
+        );
+    }
+
+    #[test]
+    fn question_do() {
+        let mut interpreter = ForthInterpreter::new();
+
+        interpreter.execute(": upto 0 ?do i loop ;").unwrap();
+
+        interpreter.execute("0 upto").unwrap();
+        assert!(interpreter.get_stack_dump().is_empty());
+
+        interpreter.execute("2 upto").unwrap();
+        assert_eq!(
+            interpreter.get_stack_dump(),
+            &Stack::from(vec![0.into(), 1.into()])
+        );
+    }
+
+    #[test]
+    fn plus_loop() {
+        let mut interpreter = ForthInterpreter::new();
+
+        interpreter.execute(": evens 10 0 do i 2 +loop ;").unwrap();
+        interpreter.execute("evens").unwrap();
+        assert_eq!(
+            interpreter.get_stack_dump(),
+            &Stack::from(vec![0.into(), 2.into(), 4.into(), 6.into(), 8.into()])
+        );
+        interpreter.clear_state();
+
+        interpreter.execute(": down 0 10 do i -2 +loop ;").unwrap();
+        interpreter.execute("down").unwrap();
+        assert_eq!(
+            interpreter.get_stack_dump(),
+            &Stack::from(vec![
+                10.into(),
+                8.into(),
+                6.into(),
+                4.into(),
+                2.into(),
+                0.into()
+            ])
+        );
+        interpreter.clear_state();
+
+        interpreter.execute(": once 1 0 do i 5 +loop ;").unwrap();
+        interpreter.execute("once").unwrap();
+        assert_eq!(interpreter.get_stack_dump(), &Stack::from(vec![0.into()]));
+    }
+
+    #[test]
+    fn leave() {
+        let mut interpreter = ForthInterpreter::new();
+
+        interpreter
+            .execute(": first 10 0 do i dup 3 = if leave then loop ;")
+            .unwrap();
+        interpreter.execute("first").unwrap();
+        assert_eq!(
+            interpreter.get_stack_dump(),
+            &Stack::from(vec![0.into(), 1.into(), 2.into(), 3.into()])
+        );
+        interpreter.clear_state();
+
+        interpreter
+            .execute(": inner 2 0 do 10 0 do i leave loop loop 5 ;")
+            .unwrap();
+        interpreter.execute("inner").unwrap();
+        assert_eq!(
+            interpreter.get_stack_dump(),
+            &Stack::from(vec![0.into(), 0.into(), 5.into()])
+        );
+        assert!(interpreter.return_stack.is_empty());
+    }
+
+    #[test]
+    fn unloop() {
+        let mut interpreter = ForthInterpreter::new();
+
+        interpreter
+            .execute(": params 3 0 do unloop i loop ;")
+            .unwrap();
+        assert!(matches!(
+            interpreter.execute("params"),
+            Err(ForthError::ReturnStackUnderflow)
+        ));
+    }
+
+    #[test]
+    fn index_outside_loop() {
+        let mut interpreter = ForthInterpreter::new();
+
+        assert!(matches!(
+            interpreter.execute("i"),
+            Err(ForthError::ReturnStackUnderflow)
+        ));
+        assert!(matches!(
+            interpreter.execute("leave"),
+            Err(ForthError::Leave)
+        ));
+    }
+}
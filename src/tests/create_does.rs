@@ -0,0 +1,121 @@
+#[cfg(test)]
+mod create_does_tests {
+    use crate::{errors::ForthError, ForthInterpreter, Literal, Stack};
+
+    #[test]
+    fn create_with_comma() {
+        let mut interpreter = ForthInterpreter::new();
+
+        interpreter
+            .execute("create primes 2 , 3 , 5 , 7 ,")
+            .unwrap();
+        interpreter
+            .execute("primes @ primes 1 cells + @ primes 3 cells + @")
+            .unwrap();
+        assert_eq!(
+            interpreter.get_stack_dump(),
+            &Stack::from(vec![2.into(), 3.into(), 7.into()])
+        );
+    }
+
+    #[test]
+    fn create_with_allot() {
+        let mut interpreter = ForthInterpreter::new();
+
+        interpreter.execute("create buffer 4 cells allot").unwrap();
+        interpreter
+            .execute("42 buffer 3 cells + ! buffer 3 cells + @ buffer @")
+            .unwrap();
+        assert_eq!(
+            interpreter.get_stack_dump(),
+            &Stack::from(vec![42.into(), 0.into()])
+        );
+
+        assert!(matches!(
+            interpreter.execute("buffer 4 cells + @"),
+            Err(ForthError::IndexOutOfBound)
+        ));
+    }
+
+    #[test]
+    fn does() {
+        let mut interpreter = ForthInterpreter::new();
+
+        interpreter
+            .execute(": const create , does> @ ; 10 const ten 20 const twenty")
+            .unwrap();
+        interpreter.execute("ten twenty + ten").unwrap();
+        assert_eq!(
+            interpreter.get_stack_dump(),
+            &Stack::from(vec![30.into(), 10.into()])
+        );
+    }
+
+    #[test]
+    fn lookup_table() {
+        let mut interpreter = ForthInterpreter::new();
+
+        interpreter
+            .execute(
+                ": table ( n*x n -- ) create 0 ?do , loop does> ( i -- x ) swap cells + @ ;
+                 300 200 100 3 table hundreds
+                 0 hundreds 2 hundreds",
+            )
+            .unwrap();
+        assert_eq!(
+            interpreter.get_stack_dump(),
+            &Stack::from(vec![100.into(), 300.into()])
+        );
+    }
+
+    #[test]
+    fn enumeration() {
+        let mut interpreter = ForthInterpreter::new();
+
+        interpreter
+            .execute(
+                "variable next-id
+                 : enum create next-id @ , 1 next-id +! does> @ ;
+                 enum red enum green enum blue
+                 blue red green",
+            )
+            .unwrap();
+        assert_eq!(
+            interpreter.get_stack_dump(),
+            &Stack::from(vec![2.into(), 0.into(), 1.into()])
+        );
+    }
+
+    #[test]
+    fn data_address_comes_first() {
+        let mut interpreter = ForthInterpreter::new();
+
+        interpreter
+            .execute(": addr-of create 7 , does> ; addr-of thing thing")
+            .unwrap();
+        assert!(matches!(
+            interpreter.get_last_literal().unwrap(),
+            Literal::Pointer(_)
+        ));
+        interpreter.execute("@").unwrap();
+        assert_eq!(interpreter.get_stack_dump(), &Stack::from(vec![7.into()]));
+    }
+
+    #[test]
+    fn misuse() {
+        let mut interpreter = ForthInterpreter::new();
+
+        assert!(matches!(
+            interpreter.execute("does>"),
+            Err(ForthError::CompileOnly)
+        ));
+        assert!(matches!(
+            interpreter.execute(": bad 1 if does> then ;"),
+            Err(ForthError::ControlStructureMismatch)
+        ));
+        assert!(matches!(
+            interpreter.execute(": orphan does> 1 ; orphan"),
+            Err(ForthError::DoesWithoutCreate)
+        ));
+    }
+}
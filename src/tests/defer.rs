@@ -0,0 +1,152 @@
+#[cfg(test)]
+mod defer_tests {
+    use crate::{errors::ForthError, ForthInterpreter, Stack};
+
+    #[test]
+    fn defer_and_is() {
+        let mut interpreter = ForthInterpreter::new();
+
+        interpreter
+            .execute("defer greet : hello 1 ; : goodbye 2 ; : run greet greet ;")
+            .unwrap();
+        interpreter.execute(r#"s" hello" is greet run"#).unwrap();
+        interpreter.execute(r#"s" goodbye" is greet run"#).unwrap();
+        assert_eq!(
+            interpreter.get_stack_dump(),
+            &Stack::from(vec![1.into(), 1.into(), 2.into(), 2.into()])
+        );
+    }
+
+    #[test]
+    fn is_inside_definition() {
+        let mut interpreter = ForthInterpreter::new();
+
+        interpreter
+            .execute(r#"defer op : set-op ( c-addr u -- ) is op ; s" +" set-op 3 4 op"#)
+            .unwrap();
+        interpreter.execute(r#"s" *" set-op 3 4 op"#).unwrap();
+        assert_eq!(
+            interpreter.get_stack_dump(),
+            &Stack::from(vec![7.into(), 12.into()])
+        );
+    }
+
+    #[test]
+    fn mutual_recursion() {
+        let mut interpreter = ForthInterpreter::new();
+
+        interpreter
+            .execute(
+                r#"defer odd?
+                 : even? dup 0 = if drop -1 else 1 - odd? then ;
+                 : (odd?) dup 0 = if drop 0 else 1 - even? then ;
+                 s" (odd?)" is odd?
+                 4 even? 7 even? 7 odd?"#,
+            )
+            .unwrap();
+        assert_eq!(
+            interpreter.get_stack_dump(),
+            &Stack::from(vec![(-1).into(), 0.into(), (-1).into()])
+        );
+    }
+
+    #[test]
+    fn action_of_and_defer_fetch_store() {
+        let mut interpreter = ForthInterpreter::new();
+
+        interpreter.execute(r#"defer d s" dup" is d"#).unwrap();
+        interpreter.execute("action-of d swap @").unwrap();
+        interpreter.execute(r#"s" d" defer@ swap @"#).unwrap();
+        interpreter
+            .execute(": current action-of d ; current swap @")
+            .unwrap();
+        assert_eq!(
+            interpreter.get_stack_dump(),
+            &Stack::from(vec![
+                3.into(),
+                ('d' as i64).into(),
+                3.into(),
+                ('d' as i64).into(),
+                3.into(),
+                ('d' as i64).into()
+            ])
+        );
+        interpreter.clear_state();
+
+        interpreter
+            .execute(r#"defer d s" negate" s" d" defer! 5 d"#)
+            .unwrap();
+        assert_eq!(
+            interpreter.get_stack_dump(),
+            &Stack::from(vec![(-5).into()])
+        );
+    }
+
+    #[test]
+    fn uninitialized_defer() {
+        let mut interpreter = ForthInterpreter::new();
+
+        interpreter.execute("defer hook : run hook ;").unwrap();
+        assert!(matches!(
+            interpreter.execute("run"),
+            Err(ForthError::UninitializedDefer(name)) if name == "hook"
+        ));
+        assert!(matches!(
+            interpreter.execute("action-of hook"),
+            Err(ForthError::UninitializedDefer(name)) if name == "hook"
+        ));
+        assert!(matches!(
+            interpreter.execute("' hook defer@"),
+            Err(ForthError::UninitializedDefer(name)) if name == "hook"
+        ));
+        assert!(matches!(
+            interpreter.execute(": test action-of hook if 1 then ; test"),
+            Err(ForthError::UninitializedDefer(name)) if name == "hook"
+        ));
+    }
+
+    #[test]
+    fn invalid_names() {
+        let mut interpreter = ForthInterpreter::new();
+
+        interpreter.execute("defer hook : plain ;").unwrap();
+        assert!(matches!(
+            interpreter.execute(r#"s" dup" is plain"#),
+            Err(ForthError::NotADefer(name)) if name == "plain"
+        ));
+        assert!(matches!(
+            interpreter.execute(r#"s" plain" defer@"#),
+            Err(ForthError::NotADefer(name)) if name == "plain"
+        ));
+        assert!(matches!(
+            interpreter.execute(r#"s" nothing" is hook"#),
+            Err(ForthError::UnknownWord(name)) if name == "nothing"
+        ));
+    }
+
+    #[test]
+    fn redefinition_replaces_defer() {
+        let mut interpreter = ForthInterpreter::new();
+
+        interpreter
+            .execute("defer thing : thing 3 ; thing")
+            .unwrap();
+        assert_eq!(interpreter.get_stack_dump(), &Stack::from(vec![3.into()]));
+    }
+
+    #[test]
+    fn is_ignores_redefined_defer_words() {
+        let mut interpreter = ForthInterpreter::new();
+
+        interpreter
+            .execute(": defer! drop drop drop ; : defer@ drop drop 0 ;")
+            .unwrap();
+        interpreter
+            .execute("defer d : set is d ; ' dup is d ' negate set 4 d action-of d ' negate =")
+            .unwrap();
+        assert_eq!(
+            interpreter.get_stack_dump(),
+            &Stack::from(vec![(-4).into(), (-1).into()])
+        );
+    }
+}
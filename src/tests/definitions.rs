@@ -0,0 +1,85 @@
+#[cfg(test)]
+mod definition_tests {
+    use crate::{ForthInterpreter, Stack};
+
+    #[test]
+    fn expression_then_statement() {
+        let mut interpreter = ForthInterpreter::new();
+
+        interpreter
+            .execute(": foo 1 2 + dup 3 = if 10 then 4 ;")
+            .unwrap();
+        interpreter.execute("foo").unwrap();
+        assert_eq!(
+            interpreter.get_stack_dump(),
+            &Stack::from(vec![3.into(), 10.into(), 4.into()])
+        );
+    }
+
+    #[test]
+    fn several_statements() {
+        let mut interpreter = ForthInterpreter::new();
+
+        interpreter
+            .execute(": bar dup 0 = if 100 swap then 1 = if 200 else 300 then 5 ;")
+            .unwrap();
+
+        interpreter.execute("0 bar").unwrap();
+        assert_eq!(
+            interpreter.get_stack_dump(),
+            &Stack::from(vec![100.into(), 300.into(), 5.into()])
+        );
+        interpreter.execute("drop drop drop").unwrap();
+
+        interpreter.execute("1 bar").unwrap();
+        assert_eq!(
+            interpreter.get_stack_dump(),
+            &Stack::from(vec![200.into(), 5.into()])
+        );
+    }
+
+    #[test]
+    fn empty_body() {
+        let mut interpreter = ForthInterpreter::new();
+
+        interpreter.execute(": nothing ;").unwrap();
+        interpreter.execute("1 nothing").unwrap();
+        assert_eq!(interpreter.get_stack_dump(), &Stack::from(vec![1.into()]));
+    }
+
+    #[test]
+    fn latest_definition_wins_across_kinds() {
+        let mut interpreter = ForthInterpreter::new();
+
+        interpreter.execute("5 constant foo : foo 6 ; foo").unwrap();
+        interpreter.execute(": bar 1 ; 7 constant bar bar").unwrap();
+        interpreter.execute("variable baz : baz 8 ; baz").unwrap();
+        interpreter
+            .execute("9 constant qux variable qux qux @")
+            .unwrap();
+        assert_eq!(
+            interpreter.get_stack_dump(),
+            &Stack::from(vec![6.into(), 7.into(), 8.into(), 0.into()])
+        );
+    }
+
+    #[test]
+    fn redefined_variables() {
+        let mut interpreter = ForthInterpreter::new();
+
+        interpreter
+            .execute("variable x 1 x ! variable x 2 x ! x @")
+            .unwrap();
+        assert_eq!(interpreter.get_stack_dump(), &Stack::from(vec![2.into()]));
+
+        interpreter
+            .execute("drop 3 x ! marker gone variable x 4 x ! gone x @")
+            .unwrap();
+        assert_eq!(interpreter.get_stack_dump(), &Stack::from(vec![3.into()]));
+
+        interpreter
+            .execute("drop 5 constant c marker gone : c 6 ; gone c")
+            .unwrap();
+        assert_eq!(interpreter.get_stack_dump(), &Stack::from(vec![5.into()]));
+    }
+}
@@ -14,7 +14,7 @@ mod arrays_chapter {
                 .execute(&format!("{} numbers {} cells + !", i * 10, i))
                 .unwrap();
             interpreter
-                .execute(&format!("numbers {} cells + @", i))
+                .execute(&format!("numbers {i} cells + @"))
                 .unwrap();
             assert_eq!(
                 interpreter.get_unary_operand().unwrap(),
@@ -27,7 +27,7 @@ mod arrays_chapter {
             interpreter
                 .execute(&format!("{} {} number !", i * 10, i))
                 .unwrap();
-            interpreter.execute(&format!("{} number @", i)).unwrap()
+            interpreter.execute(&format!("{i} number @")).unwrap();
         }
     }
 }
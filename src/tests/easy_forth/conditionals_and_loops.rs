@@ -54,7 +54,7 @@ mod defining_words_chapter {
         let mut interpreter = ForthInterpreter::new();
 
         interpreter
-            .execute(r#": buzz? 5 mod 0 = if 1 then ;"#)
+            .execute(": buzz? 5 mod 0 = if 1 then ;")
             .unwrap();
 
         interpreter.execute("3 buzz?").unwrap();
@@ -0,0 +1,77 @@
+#[cfg(test)]
+mod evaluate_tests {
+    use crate::{errors::ForthError, ForthInterpreter, Stack};
+
+    #[test]
+    fn evaluate_runs_the_string() {
+        let mut interpreter = ForthInterpreter::new();
+
+        interpreter.execute(r#"s" 1 2 +" evaluate 10 *"#).unwrap();
+        assert_eq!(interpreter.get_stack_dump(), &Stack::from(vec![30.into()]));
+    }
+
+    #[test]
+    fn evaluate_can_define_words() {
+        let mut interpreter = ForthInterpreter::new();
+
+        interpreter
+            .execute(r#"s" : square dup * ;" evaluate 7 square"#)
+            .unwrap();
+        assert_eq!(interpreter.get_stack_dump(), &Stack::from(vec![49.into()]));
+    }
+
+    #[test]
+    fn evaluate_inside_definitions() {
+        let mut interpreter = ForthInterpreter::new();
+
+        interpreter
+            .execute(r#": run ( -- n ) s" 6 7 *" evaluate ; run run +"#)
+            .unwrap();
+        assert_eq!(interpreter.get_stack_dump(), &Stack::from(vec![84.into()]));
+    }
+
+    #[test]
+    fn evaluate_nests_and_restores_the_source() {
+        let mut interpreter = ForthInterpreter::new();
+
+        interpreter
+            .execute(r#": inner s" source swap drop" evaluate ;"#)
+            .unwrap();
+        interpreter
+            .execute(r#"s" inner 100" evaluate source swap drop"#)
+            .unwrap();
+        assert_eq!(
+            interpreter.get_stack_dump(),
+            &Stack::from(vec![16.into(), 100.into(), 39.into()])
+        );
+    }
+
+    #[test]
+    fn evaluate_spans_lines() {
+        let mut interpreter = ForthInterpreter::new();
+
+        interpreter
+            .execute(r#"s\" 1 ( skipped\n ) 2 \\ ignored\n3" evaluate"#)
+            .unwrap();
+        assert_eq!(
+            interpreter.get_stack_dump(),
+            &Stack::from(vec![1.into(), 2.into(), 3.into()])
+        );
+    }
+
+    #[test]
+    fn errors_propagate_and_restore_the_source() {
+        let mut interpreter = ForthInterpreter::new();
+
+        assert!(matches!(
+            interpreter.execute(r#"s" 1 nonsense" evaluate"#),
+            Err(ForthError::UnknownWord(name)) if name == "nonsense"
+        ));
+        assert!(matches!(
+            interpreter.execute("evaluate"),
+            Err(ForthError::StackUnderflow)
+        ));
+        interpreter.execute("5").unwrap();
+        assert_eq!(interpreter.get_stack_dump(), &Stack::from(vec![5.into()]));
+    }
+}
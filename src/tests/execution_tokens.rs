@@ -0,0 +1,153 @@
+#[cfg(test)]
+mod execution_tokens_tests {
+    use crate::{errors::ForthError, ForthInterpreter, Literal, Stack};
+
+    #[test]
+    fn tick_and_execute() {
+        let mut interpreter = ForthInterpreter::new();
+
+        interpreter.execute(": square dup * ;").unwrap();
+        interpreter
+            .execute("3 ' square execute 4 ' + execute")
+            .unwrap();
+        assert_eq!(interpreter.get_stack_dump(), &Stack::from(vec![13.into()]));
+    }
+
+    #[test]
+    fn bracket_tick() {
+        let mut interpreter = ForthInterpreter::new();
+
+        interpreter
+            .execute(": apply ( n xt -- ) execute ; : twice ['] dup apply + ;")
+            .unwrap();
+        interpreter.execute("21 twice").unwrap();
+        assert_eq!(interpreter.get_stack_dump(), &Stack::from(vec![42.into()]));
+
+        assert!(matches!(
+            interpreter.execute("['] dup"),
+            Err(ForthError::CompileOnly)
+        ));
+    }
+
+    #[test]
+    fn token_survives_redefinition() {
+        let mut interpreter = ForthInterpreter::new();
+
+        interpreter
+            .execute(": answer 42 ; ' answer : answer 0 ;")
+            .unwrap();
+        interpreter.execute("execute answer").unwrap();
+        assert_eq!(
+            interpreter.get_stack_dump(),
+            &Stack::from(vec![42.into(), 0.into()])
+        );
+    }
+
+    #[test]
+    fn tokens_compare_by_identity() {
+        let mut interpreter = ForthInterpreter::new();
+
+        interpreter
+            .execute(": a ; : b ; ' a ' a = ' a ' b = ' dup ' dup = ' dup ' drop =")
+            .unwrap();
+        assert_eq!(
+            interpreter.get_stack_dump(),
+            &Stack::from(vec![(-1).into(), 0.into(), (-1).into(), 0.into()])
+        );
+    }
+
+    #[test]
+    fn stored_in_variable() {
+        let mut interpreter = ForthInterpreter::new();
+
+        interpreter
+            .execute("variable callback ' negate callback ! 5 callback @ execute")
+            .unwrap();
+        assert_eq!(
+            interpreter.get_stack_dump(),
+            &Stack::from(vec![(-5).into()])
+        );
+        assert!(matches!(
+            interpreter.get_vars_dump()[0].value,
+            Some(Literal::ExecutionToken(_))
+        ));
+    }
+
+    #[test]
+    fn dispatch_table() {
+        let mut interpreter = ForthInterpreter::new();
+
+        interpreter
+            .execute(
+                "create ops ' + , ' - , ' * ,
+                 : dispatch ( a b n -- r ) cells ops + @ execute ;
+                 10 3 0 dispatch 10 3 1 dispatch 10 3 2 dispatch",
+            )
+            .unwrap();
+        assert_eq!(
+            interpreter.get_stack_dump(),
+            &Stack::from(vec![13.into(), 7.into(), 30.into()])
+        );
+
+        interpreter.clear_state();
+        interpreter
+            .execute(
+                "variable table 2 cells allot ' dup table 1 cells + ! 7 table 1 cells + @ execute",
+            )
+            .unwrap();
+        assert_eq!(
+            interpreter.get_stack_dump(),
+            &Stack::from(vec![7.into(), 7.into()])
+        );
+    }
+
+    #[test]
+    fn deferred_words_take_tokens() {
+        let mut interpreter = ForthInterpreter::new();
+
+        interpreter
+            .execute("defer op : set-op ( xt -- ) is op ; ' + set-op 3 4 op ' * set-op 3 4 op")
+            .unwrap();
+        interpreter.execute("action-of op ' * =").unwrap();
+        interpreter.execute("' op defer@ ' * =").unwrap();
+        interpreter.execute("' negate ' op defer! 5 op").unwrap();
+        assert_eq!(
+            interpreter.get_stack_dump(),
+            &Stack::from(vec![
+                7.into(),
+                12.into(),
+                (-1).into(),
+                (-1).into(),
+                (-5).into()
+            ])
+        );
+
+        interpreter.execute(": plain ;").unwrap();
+        assert!(matches!(
+            interpreter.execute("' dup ' plain defer!"),
+            Err(ForthError::NotADefer(name)) if name == "plain"
+        ));
+        assert!(matches!(
+            interpreter.execute("' plain defer@"),
+            Err(ForthError::NotADefer(name)) if name == "plain"
+        ));
+    }
+
+    #[test]
+    fn errors() {
+        let mut interpreter = ForthInterpreter::new();
+
+        assert!(matches!(
+            interpreter.execute("' frobnicate"),
+            Err(ForthError::UnknownWord(name)) if name == "frobnicate"
+        ));
+        assert!(matches!(
+            interpreter.execute("5 execute"),
+            Err(ForthError::InvalidOperands)
+        ));
+        assert!(matches!(
+            interpreter.execute("'"),
+            Err(ForthError::MissingName)
+        ));
+    }
+}
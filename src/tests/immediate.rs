@@ -0,0 +1,111 @@
+#[cfg(test)]
+mod immediate_tests {
+    use crate::{errors::ForthError, parser::State, ForthInterpreter, Stack};
+
+    #[test]
+    fn immediate_word_runs_while_compiling() {
+        let mut interpreter = ForthInterpreter::new();
+
+        interpreter.execute(": marker-42 42 ; immediate").unwrap();
+        interpreter.execute(": foo marker-42 1 ;").unwrap();
+        assert_eq!(interpreter.get_stack_dump(), &Stack::from(vec![42.into()]));
+
+        interpreter.execute("foo").unwrap();
+        assert_eq!(
+            interpreter.get_stack_dump(),
+            &Stack::from(vec![42.into(), 1.into()])
+        );
+    }
+
+    #[test]
+    fn redefinition_is_not_immediate() {
+        let mut interpreter = ForthInterpreter::new();
+
+        interpreter
+            .execute(": seven 7 ; immediate : seven 8 ;")
+            .unwrap();
+        interpreter.execute(": foo seven ;").unwrap();
+        assert!(interpreter.get_stack_dump().is_empty());
+    }
+
+    #[test]
+    fn state() {
+        let mut interpreter = ForthInterpreter::new();
+
+        interpreter
+            .execute(": compiling? state @ ; immediate compiling? : foo compiling? ;")
+            .unwrap();
+        assert_eq!(
+            interpreter.get_stack_dump(),
+            &Stack::from(vec![0.into(), (-1).into()])
+        );
+    }
+
+    #[test]
+    fn brackets_and_literal() {
+        let mut interpreter = ForthInterpreter::new();
+
+        interpreter
+            .execute(": seconds-per-day [ 60 60 * 24 * ] literal ;")
+            .unwrap();
+        assert!(interpreter.get_stack_dump().is_empty());
+        assert_eq!(interpreter.get_state(), State::Interpret);
+
+        interpreter.execute("seconds-per-day").unwrap();
+        assert_eq!(
+            interpreter.get_stack_dump(),
+            &Stack::from(vec![86400.into()])
+        );
+    }
+
+    #[test]
+    fn postpone_immediate_word() {
+        let mut interpreter = ForthInterpreter::new();
+
+        interpreter
+            .execute(": unless postpone invert postpone if ; immediate")
+            .unwrap();
+        interpreter
+            .execute(": check unless 100 else 200 then ; 0 check -1 check")
+            .unwrap();
+        assert_eq!(
+            interpreter.get_stack_dump(),
+            &Stack::from(vec![100.into(), 200.into()])
+        );
+    }
+
+    #[test]
+    fn postpone_normal_word() {
+        let mut interpreter = ForthInterpreter::new();
+
+        interpreter
+            .execute(": compile-square postpone dup postpone * ; immediate")
+            .unwrap();
+        interpreter
+            .execute(": square compile-square ; 7 square")
+            .unwrap();
+        assert_eq!(interpreter.get_stack_dump(), &Stack::from(vec![49.into()]));
+    }
+
+    #[test]
+    fn compile_only() {
+        let mut interpreter = ForthInterpreter::new();
+
+        assert!(matches!(
+            interpreter.execute("5 literal"),
+            Err(ForthError::CompileOnly)
+        ));
+        assert!(matches!(
+            interpreter.execute("postpone dup"),
+            Err(ForthError::CompileOnly)
+        ));
+        assert!(matches!(
+            interpreter.execute("]"),
+            Err(ForthError::CompileOnly)
+        ));
+        assert!(matches!(
+            interpreter.execute(": foo postpone frobnicate ;"),
+            Err(ForthError::UnknownWord(name)) if name == "frobnicate"
+        ));
+    }
+}
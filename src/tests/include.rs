@@ -0,0 +1,145 @@
+#[cfg(test)]
+mod include_tests {
+    use std::{fs, path::PathBuf};
+
+    use crate::{errors::ForthError, ForthInterpreter, Stack};
+
+    /// Writes `files` into a fresh directory named after the test and returns it.
+    fn source_tree(test: &str, files: &[(&str, &str)]) -> PathBuf {
+        let root =
+            std::env::temp_dir().join(format!("forth-include-{}-{}", std::process::id(), test));
+        let _ = fs::remove_dir_all(&root);
+        for (name, text) in files {
+            let path = root.join(name);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, text).unwrap();
+        }
+        root
+    }
+
+    #[test]
+    fn include_file_loads_definitions() {
+        let root = source_tree(
+            "api",
+            &[(
+                "square.fs",
+                "\\ squares a number\n: square ( n -- n*n )\n  dup * ;\n",
+            )],
+        );
+        let mut interpreter = ForthInterpreter::new();
+
+        interpreter.include_file(root.join("square.fs")).unwrap();
+        interpreter.execute("9 square").unwrap();
+        assert_eq!(interpreter.get_stack_dump(), &Stack::from(vec![81.into()]));
+    }
+
+    #[test]
+    fn paths_are_relative_to_the_including_file() {
+        let root = source_tree(
+            "relative",
+            &[
+                ("main.fs", "include lib/util.fs\n: main double inc ;\n"),
+                ("lib/util.fs", "include helpers.fs\n: double 2 * ;\n"),
+                ("lib/helpers.fs", ": inc 1 + ;\n"),
+            ],
+        );
+        let mut interpreter = ForthInterpreter::new();
+
+        interpreter.include_file(root.join("main.fs")).unwrap();
+        interpreter.execute("20 main").unwrap();
+        assert_eq!(interpreter.get_stack_dump(), &Stack::from(vec![41.into()]));
+    }
+
+    #[test]
+    fn included_takes_a_string() {
+        let root = source_tree("included", &[("seven.fs", "7\n")]);
+        let mut interpreter = ForthInterpreter::new();
+
+        let path = root.join("seven.fs");
+        interpreter
+            .execute(&format!("s\" {}\" included 1 +", path.display()))
+            .unwrap();
+        assert_eq!(interpreter.get_stack_dump(), &Stack::from(vec![8.into()]));
+    }
+
+    #[test]
+    fn require_includes_once() {
+        let root = source_tree(
+            "require",
+            &[
+                (
+                    "main.fs",
+                    "require one.fs\nrequire ./one.fs\ninclude one.fs\n",
+                ),
+                ("one.fs", "1\n"),
+            ],
+        );
+        let mut interpreter = ForthInterpreter::new();
+
+        interpreter.include_file(root.join("main.fs")).unwrap();
+        assert_eq!(
+            interpreter.get_stack_dump(),
+            &Stack::from(vec![1.into(), 1.into()])
+        );
+    }
+
+    #[test]
+    fn cyclic_includes_are_refused() {
+        let root = source_tree(
+            "cycle",
+            &[
+                ("self.fs", "1\ninclude self.fs\n"),
+                ("ping.fs", ": ping ;\ninclude pong.fs\n"),
+                ("pong.fs", ": pong ;\ninclude ping.fs\n"),
+            ],
+        );
+        let mut interpreter = ForthInterpreter::new();
+
+        let error = interpreter.include_file(root.join("self.fs")).unwrap_err();
+        assert!(matches!(
+            error,
+            ForthError::InFile(_, 2, error) if matches!(*error, ForthError::CannotInclude(..))
+        ));
+
+        let error = interpreter.include_file(root.join("ping.fs")).unwrap_err();
+        let pong = root.join("pong.fs").canonicalize().unwrap();
+        assert!(matches!(
+            error,
+            ForthError::InFile(file, 2, error)
+                if file == pong.display().to_string()
+                    && matches!(*error, ForthError::CannotInclude(..))
+        ));
+    }
+
+    #[test]
+    fn errors_report_file_and_line() {
+        let root = source_tree(
+            "errors",
+            &[
+                ("main.fs", "1\ninclude broken.fs\n"),
+                ("broken.fs", ": fine ;\n\n  2 oops\n"),
+            ],
+        );
+        let mut interpreter = ForthInterpreter::new();
+
+        let error = interpreter.include_file(root.join("main.fs")).unwrap_err();
+        let broken = root.join("broken.fs").canonicalize().unwrap();
+        assert_eq!(
+            error.to_string(),
+            format!("{}:3: undefined word: oops", broken.display())
+        );
+        assert!(matches!(
+            error,
+            ForthError::InFile(_, 3, error) if matches!(*error, ForthError::UnknownWord(_))
+        ));
+
+        assert!(matches!(
+            interpreter.include_file(root.join("missing.fs")),
+            Err(ForthError::CannotInclude(..))
+        ));
+        assert!(matches!(
+            interpreter.execute("include"),
+            Err(ForthError::MissingName)
+        ));
+    }
+}
@@ -0,0 +1,84 @@
+#[cfg(test)]
+mod indefinite_loops_chapter {
+    use crate::{ForthInterpreter, Literal};
+
+    #[test]
+    fn begin_until() {
+        let mut interpreter = ForthInterpreter::new();
+
+        interpreter
+            .execute(": countdown begin dup 1 - dup 0 = until ;")
+            .unwrap();
+        interpreter.execute("3 countdown").unwrap();
+
+        for i in 0..=3 {
+            assert_eq!(
+                interpreter.get_unary_operand().unwrap(),
+                Literal::Integer(i)
+            );
+        }
+        interpreter.get_unary_operand().unwrap_err();
+    }
+
+    #[test]
+    fn begin_until_runs_at_least_once() {
+        let mut interpreter = ForthInterpreter::new();
+
+        interpreter.execute(": once begin 7 -1 until ;").unwrap();
+        interpreter.execute("once").unwrap();
+
+        assert_eq!(
+            interpreter.get_unary_operand().unwrap(),
+            Literal::Integer(7)
+        );
+        interpreter.get_unary_operand().unwrap_err();
+    }
+
+    #[test]
+    fn begin_while_repeat() {
+        let mut interpreter = ForthInterpreter::new();
+
+        interpreter
+            .execute(": halve begin dup 1 > while 2 / repeat ;")
+            .unwrap();
+
+        interpreter.execute("100 halve").unwrap();
+        assert_eq!(
+            interpreter.get_unary_operand().unwrap(),
+            Literal::Integer(1)
+        );
+        interpreter.get_unary_operand().unwrap_err();
+
+        interpreter.execute("0 halve").unwrap();
+        assert_eq!(
+            interpreter.get_unary_operand().unwrap(),
+            Literal::Integer(0)
+        );
+        interpreter.get_unary_operand().unwrap_err();
+    }
+
+    #[test]
+    fn begin_again() {
+        let mut interpreter = ForthInterpreter::new();
+
+        interpreter.execute(": drain begin drop again ;").unwrap();
+        interpreter.execute("1 2 3 drain").unwrap_err();
+
+        interpreter.get_unary_operand().unwrap_err();
+    }
+
+    #[test]
+    fn nested_indefinite_loops() {
+        let mut interpreter = ForthInterpreter::new();
+
+        interpreter
+            .execute(": steps 0 swap begin dup 0 > while begin swap 1 + swap 1 - dup 2 mod 0 = until repeat drop ;")
+            .unwrap();
+
+        interpreter.execute("6 steps").unwrap();
+        assert_eq!(
+            interpreter.get_unary_operand().unwrap(),
+            Literal::Integer(6)
+        );
+    }
+}
@@ -0,0 +1,134 @@
+#[cfg(test)]
+mod locals_tests {
+    use crate::{errors::ForthError, ForthInterpreter, Stack};
+
+    #[test]
+    fn locals_take_arguments_in_order() {
+        let mut interpreter = ForthInterpreter::new();
+
+        interpreter
+            .execute(": diff {: a b -- n :} a b - ; 10 3 diff")
+            .unwrap();
+        assert_eq!(interpreter.get_stack_dump(), &Stack::from(vec![7.into()]));
+    }
+
+    #[test]
+    fn uninitialized_locals_and_to() {
+        let mut interpreter = ForthInterpreter::new();
+
+        interpreter
+            .execute(
+                ": sum-of-squares {: a b | tmp -- n :}
+                   tmp a a * + to tmp
+                   b b * +to tmp
+                   tmp ;",
+            )
+            .unwrap();
+        interpreter.execute("3 4 sum-of-squares").unwrap();
+        assert_eq!(interpreter.get_stack_dump(), &Stack::from(vec![25.into()]));
+    }
+
+    #[test]
+    fn plus_to_ignores_redefined_addition() {
+        let mut interpreter = ForthInterpreter::new();
+
+        interpreter
+            .execute(": + * ; : bump {: a :} 5 +to a a ; 2 bump")
+            .unwrap();
+        assert_eq!(interpreter.get_stack_dump(), &Stack::from(vec![7.into()]));
+    }
+
+    #[test]
+    fn locals_are_isolated_across_recursion() {
+        let mut interpreter = ForthInterpreter::new();
+
+        interpreter
+            .execute(": fact {: n :} n 1 > if n 1 - recurse n * else 1 then ;")
+            .unwrap();
+        interpreter
+            .execute(": outer {: x :} 5 fact x ; 7 outer")
+            .unwrap();
+        assert_eq!(
+            interpreter.get_stack_dump(),
+            &Stack::from(vec![120.into(), 7.into()])
+        );
+    }
+
+    #[test]
+    fn locals_shadow_words_only_inside_their_definition() {
+        let mut interpreter = ForthInterpreter::new();
+
+        interpreter
+            .execute("5 value n : twice {: n :} n n + ; 3 twice n")
+            .unwrap();
+        assert_eq!(
+            interpreter.get_stack_dump(),
+            &Stack::from(vec![6.into(), 5.into()])
+        );
+    }
+
+    #[test]
+    fn locals_are_released_on_exit() {
+        let mut interpreter = ForthInterpreter::new();
+
+        interpreter
+            .execute(": early {: a :} a 0 < if 0 exit then a ; -1 early 2 early")
+            .unwrap();
+        assert_eq!(
+            interpreter.get_stack_dump(),
+            &Stack::from(vec![0.into(), 2.into()])
+        );
+    }
+
+    #[test]
+    fn invalid_declarations() {
+        let mut interpreter = ForthInterpreter::new();
+
+        assert!(matches!(
+            interpreter.execute("{: a :}"),
+            Err(ForthError::CompileOnly)
+        ));
+        assert!(matches!(
+            interpreter.execute(": bad {: a"),
+            Err(ForthError::ControlStructureMismatch)
+        ));
+        assert!(matches!(
+            interpreter.execute(": bad {: a :} {: b :} ;"),
+            Err(ForthError::ControlStructureMismatch)
+        ));
+        assert!(matches!(
+            interpreter.execute(": needs {: a b :} a b + ; 1 needs"),
+            Err(ForthError::StackUnderflow)
+        ));
+    }
+
+    #[test]
+    fn locals_are_not_visible_in_quotations_or_does() {
+        let mut interpreter = ForthInterpreter::new();
+
+        assert!(matches!(
+            interpreter.execute(": mk {: a :} [: a ;] ;"),
+            Err(ForthError::UnknownWord(name)) if name == "a"
+        ));
+        interpreter.clear_state();
+        assert!(matches!(
+            interpreter.execute(": mk {: n :} create n , does> @ n + ;"),
+            Err(ForthError::UnknownWord(name)) if name == "n"
+        ));
+        interpreter.clear_state();
+
+        interpreter
+            .execute(": a 5 ; : mk {: a :} [: a ;] ; 1 mk constant q")
+            .unwrap();
+        interpreter
+            .execute(": other {: x y :} q execute ; 100 200 other")
+            .unwrap();
+        interpreter
+            .execute(": n 3 ; : mk {: n :} create n , does> @ n + ; 4 mk seven seven")
+            .unwrap();
+        assert_eq!(
+            interpreter.get_stack_dump(),
+            &Stack::from(vec![5.into(), 7.into()])
+        );
+    }
+}
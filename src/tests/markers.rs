@@ -0,0 +1,99 @@
+#[cfg(test)]
+mod markers_tests {
+    use crate::{errors::ForthError, ForthInterpreter, Stack};
+
+    #[test]
+    fn marker_discards_later_definitions() {
+        let mut interpreter = ForthInterpreter::new();
+
+        interpreter.execute(": keep 1 ; variable kept").unwrap();
+        interpreter
+            .execute("marker scratch : tmp 2 ; variable v 3 constant c 4 value w defer d")
+            .unwrap();
+        interpreter.execute("scratch").unwrap();
+
+        for name in &["tmp", "v", "c", "w", "d", "scratch"] {
+            assert!(matches!(
+                interpreter.execute(name),
+                Err(ForthError::UnknownWord(word)) if word == *name
+            ));
+        }
+        assert_eq!(interpreter.get_vars_dump().len(), 1);
+
+        interpreter.execute("keep kept @").unwrap();
+        assert_eq!(
+            interpreter.get_stack_dump(),
+            &Stack::from(vec![1.into(), 0.into()])
+        );
+    }
+
+    #[test]
+    fn marker_restores_redefined_words() {
+        let mut interpreter = ForthInterpreter::new();
+
+        interpreter
+            .execute(": greet 1 ; : shout 5 ; immediate")
+            .unwrap();
+        interpreter
+            .execute("marker experiment : greet 2 ; : shout 6 ; 10 constant greet-count")
+            .unwrap();
+        interpreter.execute("greet experiment greet").unwrap();
+        assert_eq!(
+            interpreter.get_stack_dump(),
+            &Stack::from(vec![2.into(), 1.into()])
+        );
+
+        interpreter.execute(": uses-shout shout ;").unwrap();
+        assert_eq!(
+            interpreter.get_stack_dump(),
+            &Stack::from(vec![2.into(), 1.into(), 5.into()])
+        );
+    }
+
+    #[test]
+    fn nested_markers() {
+        let mut interpreter = ForthInterpreter::new();
+
+        interpreter
+            .execute("marker outer : a 1 ; marker inner : b 2 ;")
+            .unwrap();
+        interpreter.execute("inner a").unwrap();
+        assert!(interpreter.execute("b").is_err());
+
+        interpreter.execute("outer").unwrap();
+        assert!(interpreter.execute("a").is_err());
+        assert!(interpreter.execute("inner").is_err());
+        assert_eq!(interpreter.get_stack_dump(), &Stack::from(vec![1.into()]));
+    }
+
+    #[test]
+    fn forget() {
+        let mut interpreter = ForthInterpreter::new();
+
+        interpreter
+            .execute(": one 1 ; : two 2 ; variable three : four 4 ;")
+            .unwrap();
+        interpreter.execute("forget two").unwrap();
+
+        interpreter.execute("one").unwrap();
+        assert!(interpreter.execute("two").is_err());
+        assert!(interpreter.execute("three").is_err());
+        assert!(interpreter.execute("four").is_err());
+        assert!(interpreter.get_vars_dump().is_empty());
+        assert_eq!(interpreter.get_stack_dump(), &Stack::from(vec![1.into()]));
+    }
+
+    #[test]
+    fn forget_unknown_word() {
+        let mut interpreter = ForthInterpreter::new();
+
+        assert!(matches!(
+            interpreter.execute("forget nothing"),
+            Err(ForthError::UnknownWord(name)) if name == "nothing"
+        ));
+        assert!(matches!(
+            interpreter.execute("forget dup"),
+            Err(ForthError::UnknownWord(name)) if name == "dup"
+        ));
+    }
+}
@@ -1,2 +1,27 @@
+mod anonymous;
 mod arrays;
+mod case;
+mod characters;
+mod comments;
+mod compile_state;
+mod counted_loops;
+mod create_does;
+mod defer;
+mod definitions;
 mod easy_forth;
+mod evaluate;
+mod execution_tokens;
+mod immediate;
+mod include;
+mod indefinite_loops;
+mod locals;
+mod markers;
+mod names;
+mod numbers;
+mod parsing_words;
+mod recursion;
+mod search_order;
+mod statements;
+mod strings;
+mod structures;
+mod values;
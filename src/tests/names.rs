@@ -0,0 +1,85 @@
+#[cfg(test)]
+mod name_tests {
+    use crate::{errors::ForthError, ForthInterpreter, Literal, Stack};
+
+    #[test]
+    fn punctuation_in_names() {
+        let mut interpreter = ForthInterpreter::new();
+
+        interpreter.execute(": 2dup over over ;").unwrap();
+        interpreter.execute(": 1+ 1 + ;").unwrap();
+        interpreter
+            .execute(": ?dup dup 0 = if else dup then ;")
+            .unwrap();
+        interpreter.execute(": >double 2 * ;").unwrap();
+
+        interpreter.execute("3 4 2dup").unwrap();
+        assert_eq!(
+            interpreter.get_stack_dump(),
+            &Stack::from(vec![3.into(), 4.into(), 3.into(), 4.into()])
+        );
+        interpreter.clear_state();
+
+        interpreter.execute(": 1+ 1 + ; : >double 2 * ;").unwrap();
+        interpreter.execute("5 1+ >double").unwrap();
+        assert_eq!(
+            interpreter.get_unary_operand().unwrap(),
+            Literal::Integer(12)
+        );
+    }
+
+    #[test]
+    fn names_containing_keywords() {
+        let mut interpreter = ForthInterpreter::new();
+
+        interpreter
+            .execute(": iffy 1 ; : do-it 2 ; : loops 3 ;")
+            .unwrap();
+        interpreter.execute(": all iffy do-it loops ;").unwrap();
+        interpreter.execute("all").unwrap();
+        assert_eq!(
+            interpreter.get_stack_dump(),
+            &Stack::from(vec![1.into(), 2.into(), 3.into()])
+        );
+    }
+
+    #[test]
+    fn numbers_before_names() {
+        let mut interpreter = ForthInterpreter::new();
+
+        interpreter.execute(": 10 20 ;").unwrap();
+        interpreter.execute("10 -5 +5").unwrap();
+        assert_eq!(
+            interpreter.get_stack_dump(),
+            &Stack::from(vec![10.into(), (-5).into(), 5.into()])
+        );
+    }
+
+    #[test]
+    fn user_words_shadow_native_words() {
+        let mut interpreter = ForthInterpreter::new();
+
+        interpreter.execute(": dup 42 ;").unwrap();
+        interpreter.execute("1 dup").unwrap();
+        assert_eq!(
+            interpreter.get_stack_dump(),
+            &Stack::from(vec![1.into(), 42.into()])
+        );
+    }
+
+    #[test]
+    fn unknown_word() {
+        let mut interpreter = ForthInterpreter::new();
+
+        assert!(matches!(
+            interpreter.execute("1 frobnicate"),
+            Err(ForthError::UnknownWord(name)) if name == "frobnicate"
+        ));
+
+        assert!(matches!(
+            interpreter.execute(": later frobnicate ;"),
+            Err(ForthError::UnknownWord(name)) if name == "frobnicate"
+        ));
+        assert!(!interpreter.get_user_words_dump().contains_key("later"));
+    }
+}
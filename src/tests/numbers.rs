@@ -0,0 +1,125 @@
+#[cfg(test)]
+mod numbers_tests {
+    use crate::{errors::ForthError, ForthInterpreter, Literal, Stack};
+
+    #[test]
+    fn prefixed_literals() {
+        let mut interpreter = ForthInterpreter::new();
+
+        interpreter.execute("$ff #10 %1010 'a' $-10").unwrap();
+        assert_eq!(
+            interpreter.get_stack_dump(),
+            &Stack::from(vec![
+                255.into(),
+                10.into(),
+                10.into(),
+                97.into(),
+                (-16).into()
+            ])
+        );
+    }
+
+    #[test]
+    fn base_words() {
+        let mut interpreter = ForthInterpreter::new();
+
+        interpreter.execute("base @").unwrap();
+        interpreter.execute("hex ff 10 base @ decimal").unwrap();
+        interpreter.execute("binary 101 decimal 101").unwrap();
+        assert_eq!(
+            interpreter.get_stack_dump(),
+            &Stack::from(vec![
+                10.into(),
+                255.into(),
+                16.into(),
+                16.into(),
+                5.into(),
+                101.into()
+            ])
+        );
+    }
+
+    #[test]
+    fn base_as_variable() {
+        let mut interpreter = ForthInterpreter::new();
+
+        interpreter.execute("8 base ! 17 #8 base @").unwrap();
+        assert_eq!(
+            interpreter.get_stack_dump(),
+            &Stack::from(vec![15.into(), 8.into(), 8.into()])
+        );
+
+        assert!(matches!(
+            interpreter.execute("1 base !"),
+            Err(ForthError::InvalidOperands)
+        ));
+    }
+
+    #[test]
+    fn base_applies_when_compiling() {
+        let mut interpreter = ForthInterpreter::new();
+
+        interpreter.execute("hex : mask ff and ; decimal").unwrap();
+        interpreter.execute("255 mask").unwrap();
+        assert_eq!(
+            interpreter.get_stack_dump(),
+            &Stack::from(vec![(-1).into()])
+        );
+    }
+
+    #[test]
+    fn words_are_not_numbers() {
+        let mut interpreter = ForthInterpreter::new();
+
+        assert!(matches!(
+            interpreter.execute("$fg"),
+            Err(ForthError::UnknownWord(name)) if name == "$fg"
+        ));
+    }
+
+    #[test]
+    fn arithmetic_wraps() {
+        let mut interpreter = ForthInterpreter::new();
+
+        interpreter
+            .execute(": fact dup 1 > if dup 1 - fact * then ; 150 fact")
+            .unwrap();
+        interpreter
+            .execute("$7fffffffffffffff 1 + $-7fffffffffffffff 2 -")
+            .unwrap();
+        interpreter
+            .execute("$-7fffffffffffffff 1 - dup negate over abs")
+            .unwrap();
+        let min = Literal::Integer(i64::MIN);
+        assert_eq!(
+            interpreter.get_stack_dump(),
+            &Stack::from(vec![
+                0.into(),
+                min.clone(),
+                i64::MAX.into(),
+                min.clone(),
+                min.clone(),
+                min
+            ])
+        );
+    }
+
+    #[test]
+    fn division_by_zero() {
+        let mut interpreter = ForthInterpreter::new();
+
+        assert!(matches!(
+            interpreter.execute("1 0 /"),
+            Err(ForthError::DivisionByZero)
+        ));
+        assert!(matches!(
+            interpreter.execute("1 0 mod"),
+            Err(ForthError::DivisionByZero)
+        ));
+        interpreter.execute("7 2 / 7 2 mod").unwrap();
+        assert_eq!(
+            interpreter.get_stack_dump(),
+            &Stack::from(vec![3.into(), 1.into()])
+        );
+    }
+}
@@ -0,0 +1,85 @@
+#[cfg(test)]
+mod parsing_words_tests {
+    use crate::{errors::ForthError, ForthInterpreter, Literal, Stack};
+
+    #[test]
+    fn parse_name() {
+        let mut interpreter = ForthInterpreter::new();
+
+        interpreter.execute("parse-name hello swap drop").unwrap();
+        assert_eq!(interpreter.get_stack_dump(), &Stack::from(vec![5.into()]));
+        interpreter.clear_state();
+
+        interpreter.execute("parse-name").unwrap();
+        assert_eq!(
+            interpreter.get_unary_operand().unwrap(),
+            Literal::Integer(0)
+        );
+    }
+
+    #[test]
+    fn parse_name_in_user_word() {
+        let mut interpreter = ForthInterpreter::new();
+
+        interpreter
+            .execute(": next-len parse-name swap drop ;")
+            .unwrap();
+        interpreter.execute("next-len hello 1").unwrap();
+        assert_eq!(
+            interpreter.get_stack_dump(),
+            &Stack::from(vec![5.into(), 1.into()])
+        );
+    }
+
+    #[test]
+    fn user_defining_word() {
+        let mut interpreter = ForthInterpreter::new();
+
+        interpreter.execute(": var2 variable ;").unwrap();
+        interpreter.execute("var2 x 7 x ! x @").unwrap();
+        assert_eq!(interpreter.get_stack_dump(), &Stack::from(vec![7.into()]));
+        assert_eq!(interpreter.get_vars_dump()[0].name, "x");
+    }
+
+    #[test]
+    fn parse_and_source() {
+        let mut interpreter = ForthInterpreter::new();
+
+        interpreter.execute("41 parse abc) swap @").unwrap();
+        assert_eq!(
+            interpreter.get_stack_dump(),
+            &Stack::from(vec![3.into(), ('a' as i64).into()])
+        );
+        interpreter.clear_state();
+
+        interpreter.execute("source swap drop").unwrap();
+        assert_eq!(interpreter.get_stack_dump(), &Stack::from(vec![16.into()]));
+    }
+
+    #[test]
+    fn to_in() {
+        let mut interpreter = ForthInterpreter::new();
+
+        interpreter.execute(">in @").unwrap();
+        assert_eq!(interpreter.get_stack_dump(), &Stack::from(vec![5.into()]));
+        interpreter.clear_state();
+
+        // Skipping the rest of the line by moving >in to its end.
+        interpreter.execute("1 source swap drop >in ! 2 3").unwrap();
+        assert_eq!(interpreter.get_stack_dump(), &Stack::from(vec![1.into()]));
+    }
+
+    #[test]
+    fn missing_name() {
+        let mut interpreter = ForthInterpreter::new();
+
+        assert!(matches!(
+            interpreter.execute("variable"),
+            Err(ForthError::MissingName)
+        ));
+        assert!(matches!(
+            interpreter.execute("5 constant"),
+            Err(ForthError::MissingName)
+        ));
+    }
+}
@@ -0,0 +1,130 @@
+#[cfg(test)]
+mod recursion_tests {
+    use crate::{errors::ForthError, ForthInterpreter, Literal, Stack};
+
+    #[test]
+    fn self_reference() {
+        let mut interpreter = ForthInterpreter::new();
+
+        interpreter
+            .execute(": fact dup 1 > if dup 1 - fact * then ;")
+            .unwrap();
+        interpreter.execute("5 fact").unwrap();
+        assert_eq!(
+            interpreter.get_unary_operand().unwrap(),
+            Literal::Integer(120)
+        );
+    }
+
+    #[test]
+    fn recurse() {
+        let mut interpreter = ForthInterpreter::new();
+
+        interpreter
+            .execute(": fib dup 1 > if dup 1 - recurse swap 2 - recurse + then ;")
+            .unwrap();
+        interpreter.execute("10 fib").unwrap();
+        assert_eq!(
+            interpreter.get_unary_operand().unwrap(),
+            Literal::Integer(55)
+        );
+    }
+
+    #[test]
+    fn ackermann() {
+        let mut interpreter = ForthInterpreter::new();
+
+        interpreter
+            .execute(": ack over 0 = if swap drop 1 + exit then dup 0 = if drop 1 - 1 recurse exit then over swap 1 - recurse swap 1 - swap recurse ;")
+            .unwrap();
+        interpreter.execute("2 3 ack").unwrap();
+        assert_eq!(interpreter.get_stack_dump(), &Stack::from(vec![9.into()]));
+    }
+
+    #[test]
+    fn exit_from_nested_statements() {
+        let mut interpreter = ForthInterpreter::new();
+
+        interpreter
+            .execute(": find_three 10 0 do i 3 = if i unloop exit then loop 99 ;")
+            .unwrap();
+        interpreter.execute("find_three").unwrap();
+        assert_eq!(interpreter.get_stack_dump(), &Stack::from(vec![3.into()]));
+        assert!(interpreter.return_stack.is_empty());
+        interpreter.clear_state();
+
+        interpreter
+            .execute(": deep begin 1 case 1 of 5 0 do 7 exit loop endof endcase again ;")
+            .unwrap();
+        interpreter.execute(": outer deep 8 ;").unwrap();
+        interpreter.execute("outer").unwrap();
+        assert_eq!(
+            interpreter.get_stack_dump(),
+            &Stack::from(vec![7.into(), 8.into()])
+        );
+        assert!(interpreter.return_stack.is_empty());
+    }
+
+    #[test]
+    fn outside_definition() {
+        let mut interpreter = ForthInterpreter::new();
+
+        assert!(matches!(
+            interpreter.execute("recurse"),
+            Err(ForthError::RecurseOutsideDefinition)
+        ));
+        assert!(matches!(interpreter.execute("exit"), Err(ForthError::Exit)));
+    }
+
+    #[test]
+    fn runaway_recursion() {
+        let mut interpreter = ForthInterpreter::new();
+
+        interpreter.execute(": forever 1 + recurse ;").unwrap();
+        assert!(matches!(
+            interpreter.execute("0 forever"),
+            Err(ForthError::ReturnStackOverflow)
+        ));
+
+        interpreter
+            .execute(": nested 1 0 do 1 case 1 of begin recurse 1 until endof endcase loop ;")
+            .unwrap();
+        assert!(matches!(
+            interpreter.execute("nested"),
+            Err(ForthError::ReturnStackOverflow)
+        ));
+
+        interpreter
+            .execute(": again s\" again\" evaluate ;")
+            .unwrap();
+        assert!(matches!(
+            interpreter.execute("again"),
+            Err(ForthError::ReturnStackOverflow)
+        ));
+
+        interpreter.execute(": fine 2 ; fine").unwrap();
+        assert_eq!(interpreter.get_last_literal().unwrap(), &2.into());
+    }
+
+    #[test]
+    fn deep_recursion() {
+        let mut interpreter = ForthInterpreter::new();
+
+        interpreter
+            .execute(": fact dup 1 > if dup 1 - recurse * then ; 20 fact")
+            .unwrap();
+        assert_eq!(
+            interpreter.get_last_literal().unwrap(),
+            &2_432_902_008_176_640_000_i64.into()
+        );
+
+        interpreter
+            .execute(": down dup if 1 - recurse then ; 250 down")
+            .unwrap();
+        assert_eq!(interpreter.get_last_literal().unwrap(), &0.into());
+        assert!(matches!(
+            interpreter.execute("300 down"),
+            Err(ForthError::ReturnStackOverflow)
+        ));
+    }
+}
@@ -0,0 +1,204 @@
+#[cfg(test)]
+mod search_order_tests {
+    use crate::{errors::ForthError, ForthInterpreter, Literal, Stack};
+
+    #[test]
+    fn default_order_is_forth_wordlist() {
+        let mut interpreter = ForthInterpreter::new();
+
+        interpreter
+            .execute("get-order get-current forth-wordlist")
+            .unwrap();
+        assert_eq!(
+            interpreter.get_stack_dump(),
+            &Stack::from(vec![0.into(), 1.into(), 0.into(), 0.into()])
+        );
+    }
+
+    #[test]
+    fn definitions_go_into_the_current_wordlist() {
+        let mut interpreter = ForthInterpreter::new();
+
+        interpreter
+            .execute("wordlist value lib lib set-current : helper 42 ;")
+            .unwrap();
+        interpreter.execute("forth-wordlist set-current").unwrap();
+        assert!(matches!(
+            interpreter.execute("helper"),
+            Err(ForthError::UnknownWord(name)) if name == "helper"
+        ));
+
+        interpreter
+            .execute("get-order lib swap 1 + set-order helper")
+            .unwrap();
+        assert_eq!(interpreter.get_stack_dump(), &Stack::from(vec![42.into()]));
+    }
+
+    #[test]
+    fn lookup_honors_the_order() {
+        let mut interpreter = ForthInterpreter::new();
+
+        interpreter
+            .execute("wordlist value a wordlist value b : name 0 ;")
+            .unwrap();
+        interpreter
+            .execute("a set-current : name 1 ; b set-current : name 2 ;")
+            .unwrap();
+        interpreter
+            .execute("forth-wordlist a b 3 set-order name")
+            .unwrap();
+        interpreter
+            .execute("forth-wordlist b a 3 set-order name previous name previous name")
+            .unwrap();
+        assert_eq!(
+            interpreter.get_stack_dump(),
+            &Stack::from(vec![2.into(), 1.into(), 2.into(), 0.into()])
+        );
+    }
+
+    #[test]
+    fn compiled_words_keep_the_definition_they_found() {
+        let mut interpreter = ForthInterpreter::new();
+
+        interpreter
+            .execute("vocabulary inner also inner definitions : greet 7 ;")
+            .unwrap();
+        interpreter
+            .execute("previous definitions : call-greet greet ;")
+            .unwrap_err();
+        interpreter
+            .execute("also inner : call-greet greet ; previous call-greet")
+            .unwrap();
+        assert_eq!(interpreter.get_stack_dump(), &Stack::from(vec![7.into()]));
+    }
+
+    #[test]
+    fn vocabulary_replaces_the_first_wordlist() {
+        let mut interpreter = ForthInterpreter::new();
+
+        interpreter
+            .execute("vocabulary tools also tools definitions : dup 99 ;")
+            .unwrap();
+        interpreter.execute("1 dup").unwrap();
+        interpreter.execute("forth 2 dup only").unwrap();
+        interpreter.execute("get-order").unwrap();
+        assert_eq!(
+            interpreter.get_stack_dump(),
+            &Stack::from(vec![
+                1.into(),
+                99.into(),
+                2.into(),
+                2.into(),
+                0.into(),
+                1.into()
+            ])
+        );
+    }
+
+    #[test]
+    fn vocabulary_ignores_redefined_words() {
+        let mut interpreter = ForthInterpreter::new();
+
+        interpreter
+            .execute("vocabulary tools : swap 7 ; : drop ; also tools get-order")
+            .unwrap();
+        assert_eq!(
+            interpreter.get_stack_dump(),
+            &Stack::from(vec![0.into(), 1.into(), 2.into()])
+        );
+    }
+
+    #[test]
+    fn tick_searches_the_order() {
+        let mut interpreter = ForthInterpreter::new();
+
+        interpreter
+            .execute("wordlist dup set-current : secret 5 ; forth-wordlist set-current")
+            .unwrap();
+        assert!(matches!(
+            interpreter.execute("' secret"),
+            Err(ForthError::UnknownWord(_))
+        ));
+        interpreter
+            .execute("get-order 1 + rot swap set-order ' secret execute")
+            .unwrap();
+        assert_eq!(interpreter.get_stack_dump(), &Stack::from(vec![5.into()]));
+    }
+
+    #[test]
+    fn order_errors() {
+        let mut interpreter = ForthInterpreter::new();
+
+        assert!(matches!(
+            interpreter.execute("12 set-current"),
+            Err(ForthError::InvalidOperands)
+        ));
+        assert!(matches!(
+            interpreter.execute("-2 set-order"),
+            Err(ForthError::InvalidOperands)
+        ));
+        interpreter.execute("1 2 +").unwrap();
+        interpreter.execute("0 set-order").unwrap();
+        assert!(matches!(
+            interpreter.execute("1 2 +"),
+            Err(ForthError::UnknownWord(name)) if name == "+"
+        ));
+        assert!(matches!(
+            interpreter.execute("previous"),
+            Err(ForthError::UnknownWord(_))
+        ));
+
+        let mut interpreter = ForthInterpreter::new();
+        assert!(matches!(
+            interpreter.execute(": twice dup execute execute ; ' previous twice"),
+            Err(ForthError::SearchOrderUnderflow)
+        ));
+    }
+
+    #[test]
+    fn words_in_a_wordlist_keep_their_names() {
+        let mut interpreter = ForthInterpreter::new();
+
+        interpreter
+            .execute("vocabulary tools also tools definitions defer h 5 value v : p ;")
+            .unwrap();
+        for name in ["p", "h", "v"] {
+            interpreter.execute(&format!("' {name}")).unwrap();
+            match interpreter.get_unary_operand().unwrap() {
+                Literal::ExecutionToken(token) => {
+                    assert_eq!(format!("{token:?}"), format!("xt({name})"));
+                }
+                literal => panic!("expected an execution token, got {:?}", literal),
+            }
+        }
+
+        assert!(matches!(
+            interpreter.execute("h"),
+            Err(ForthError::UninitializedDefer(name)) if name == "h"
+        ));
+        assert!(matches!(
+            interpreter.execute("action-of h"),
+            Err(ForthError::UninitializedDefer(name)) if name == "h"
+        ));
+        assert!(matches!(
+            interpreter.execute("' h defer@"),
+            Err(ForthError::UninitializedDefer(name)) if name == "h"
+        ));
+        assert!(matches!(
+            interpreter.execute("' v defer@"),
+            Err(ForthError::NotADefer(name)) if name == "v"
+        ));
+        assert!(matches!(
+            interpreter.execute("to h"),
+            Err(ForthError::NotAValue(name)) if name == "h"
+        ));
+
+        interpreter
+            .execute(r#"s" p" s" h" defer! s" h" defer@ swap @"#)
+            .unwrap();
+        assert_eq!(
+            interpreter.get_stack_dump(),
+            &Stack::from(vec![1.into(), ('p' as i64).into()])
+        );
+    }
+}
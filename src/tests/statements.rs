@@ -0,0 +1,76 @@
+#[cfg(test)]
+mod statement_tests {
+    use crate::{ForthInterpreter, Stack};
+
+    #[test]
+    fn if_inside_else() {
+        let mut interpreter = ForthInterpreter::new();
+
+        interpreter
+            .execute(": sign dup 0 < if drop -1 else 0 = if 0 else 1 then then ;")
+            .unwrap();
+
+        interpreter.execute("-5 sign 0 sign 5 sign").unwrap();
+        assert_eq!(
+            interpreter.get_stack_dump(),
+            &Stack::from(vec![(-1).into(), 0.into(), 1.into()])
+        );
+    }
+
+    #[test]
+    fn if_inside_if() {
+        let mut interpreter = ForthInterpreter::new();
+
+        interpreter
+            .execute(": both if if 1 else 2 then else drop 3 then ;")
+            .unwrap();
+
+        interpreter
+            .execute("-1 -1 both 0 -1 both 0 0 both")
+            .unwrap();
+        assert_eq!(
+            interpreter.get_stack_dump(),
+            &Stack::from(vec![1.into(), 2.into(), 3.into()])
+        );
+    }
+
+    #[test]
+    fn loop_inside_else() {
+        let mut interpreter = ForthInterpreter::new();
+
+        interpreter
+            .execute(": fill if 9 else 3 0 do 7 loop then ;")
+            .unwrap();
+
+        interpreter.execute("0 fill").unwrap();
+        assert_eq!(
+            interpreter.get_stack_dump(),
+            &Stack::from(vec![7.into(), 7.into(), 7.into()])
+        );
+    }
+
+    #[test]
+    fn if_inside_loop() {
+        let mut interpreter = ForthInterpreter::new();
+
+        interpreter
+            .execute(": odd_ones 4 0 do i 2 mod 1 = if 1 + then loop ;")
+            .unwrap();
+
+        interpreter.execute("1 odd_ones").unwrap();
+        assert_eq!(interpreter.get_stack_dump(), &Stack::from(vec![3.into()]));
+    }
+
+    #[test]
+    fn nonzero_is_true() {
+        let mut interpreter = ForthInterpreter::new();
+
+        interpreter.execute(": truthy if 1 else 0 then ;").unwrap();
+
+        interpreter.execute("5 truthy 0 truthy -1 truthy").unwrap();
+        assert_eq!(
+            interpreter.get_stack_dump(),
+            &Stack::from(vec![1.into(), 0.into(), 1.into()])
+        );
+    }
+}
@@ -0,0 +1,113 @@
+#[cfg(test)]
+mod strings_tests {
+    use crate::{errors::ForthError, ForthInterpreter, Literal};
+
+    fn pop_string(interpreter: &mut ForthInterpreter) -> String {
+        interpreter.get_string_operand().unwrap()
+    }
+
+    #[test]
+    fn s_quote() {
+        let mut interpreter = ForthInterpreter::new();
+
+        interpreter.execute(r#"s" Hello, world 42!""#).unwrap();
+        assert_eq!(pop_string(&mut interpreter), "Hello, world 42!");
+
+        interpreter.execute(r#"s" ""#).unwrap();
+        assert_eq!(pop_string(&mut interpreter), "");
+    }
+
+    #[test]
+    fn s_quote_in_definition() {
+        let mut interpreter = ForthInterpreter::new();
+
+        interpreter
+            .execute(r#": greeting s" good morning" ;"#)
+            .unwrap();
+        interpreter.execute("greeting greeting").unwrap();
+        assert_eq!(pop_string(&mut interpreter), "good morning");
+        assert_eq!(pop_string(&mut interpreter), "good morning");
+        assert!(interpreter.get_stack_dump().is_empty());
+    }
+
+    #[test]
+    fn string_cells() {
+        let mut interpreter = ForthInterpreter::new();
+
+        interpreter.execute(r#"s" abc" drop 2 + @"#).unwrap();
+        assert_eq!(
+            interpreter.get_unary_operand().unwrap(),
+            Literal::Integer('c' as i64)
+        );
+    }
+
+    #[test]
+    fn c_quote() {
+        let mut interpreter = ForthInterpreter::new();
+
+        interpreter.execute(r#": name c" forth" ; name @"#).unwrap();
+        assert_eq!(
+            interpreter.get_unary_operand().unwrap(),
+            Literal::Integer(5)
+        );
+
+        interpreter.execute("name count").unwrap();
+        assert_eq!(pop_string(&mut interpreter), "forth");
+    }
+
+    #[test]
+    fn s_backslash_quote() {
+        let mut interpreter = ForthInterpreter::new();
+
+        interpreter
+            .execute(r#": escaped s\" tab\there\n\"quoted\" \x41\\" ;"#)
+            .unwrap();
+        interpreter.execute("escaped").unwrap();
+        assert_eq!(pop_string(&mut interpreter), "tab\there\n\"quoted\" A\\");
+
+        assert!(matches!(
+            interpreter.execute(r#"s\" \xZZ""#),
+            Err(ForthError::InvalidOperands)
+        ));
+    }
+
+    #[test]
+    fn strings_leave_the_last_definition_growable() {
+        let mut interpreter = ForthInterpreter::new();
+
+        interpreter
+            .execute(r#"variable nums : hi ." hi" ; 3 cells allot 7 nums 3 + ! nums 3 + @"#)
+            .unwrap();
+        assert_eq!(interpreter.get_last_literal().unwrap(), &7.into());
+
+        interpreter
+            .execute(r#"create tbl 1 , s" x" type c" y" drop 2 , tbl 1 + @"#)
+            .unwrap();
+        assert_eq!(interpreter.get_last_literal().unwrap(), &2.into());
+    }
+
+    #[test]
+    fn printing_words() {
+        let mut interpreter = ForthInterpreter::new();
+
+        interpreter
+            .execute(r#": hello ." Hello there!" cr ; hello"#)
+            .unwrap();
+        interpreter.execute(".( compiling... ) 1").unwrap();
+        interpreter.execute(r#"s" typed" type"#).unwrap();
+        assert_eq!(
+            interpreter.get_stack_dump(),
+            &crate::Stack::from(vec![1.into()])
+        );
+    }
+
+    #[test]
+    fn dot_quote_ignores_redefined_type() {
+        let mut interpreter = ForthInterpreter::new();
+
+        interpreter
+            .execute(r#": type drop drop 42 ; : hi ." hi" ; hi"#)
+            .unwrap();
+        assert!(interpreter.get_stack_dump().is_empty());
+    }
+}
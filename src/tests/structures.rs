@@ -0,0 +1,75 @@
+#[cfg(test)]
+mod structures_tests {
+    use crate::{errors::ForthError, ForthInterpreter, Stack};
+
+    const POINT: &str = "begin-structure point field: p.x field: p.y end-structure";
+
+    #[test]
+    fn structure_reports_its_size() {
+        let mut interpreter = ForthInterpreter::new();
+
+        interpreter.execute(POINT).unwrap();
+        interpreter
+            .execute("begin-structure packet 1 +field p.kind 4 +field p.payload end-structure")
+            .unwrap();
+        interpreter.execute("point packet").unwrap();
+        assert_eq!(
+            interpreter.get_stack_dump(),
+            &Stack::from(vec![2.into(), 5.into()])
+        );
+    }
+
+    #[test]
+    fn fields_add_their_offset() {
+        let mut interpreter = ForthInterpreter::new();
+
+        interpreter.execute(POINT).unwrap();
+        interpreter.execute("10 p.x 10 p.y").unwrap();
+        assert_eq!(
+            interpreter.get_stack_dump(),
+            &Stack::from(vec![10.into(), 11.into()])
+        );
+    }
+
+    #[test]
+    fn fields_address_allotted_records() {
+        let mut interpreter = ForthInterpreter::new();
+
+        interpreter.execute(POINT).unwrap();
+        interpreter
+            .execute("create origin point allot 3 origin p.x ! 4 origin p.y !")
+            .unwrap();
+        interpreter
+            .execute(": norm2 ( addr -- n ) dup p.x @ dup * swap p.y @ dup * + ;")
+            .unwrap();
+        interpreter.execute("origin norm2").unwrap();
+        assert_eq!(interpreter.get_stack_dump(), &Stack::from(vec![25.into()]));
+    }
+
+    #[test]
+    fn structures_ignore_redefined_words() {
+        let mut interpreter = ForthInterpreter::new();
+
+        interpreter.execute(POINT).unwrap();
+        interpreter.execute(": + * ; : @ drop 0 ;").unwrap();
+        interpreter.execute("10 p.y point").unwrap();
+        assert_eq!(
+            interpreter.get_stack_dump(),
+            &Stack::from(vec![11.into(), 2.into()])
+        );
+    }
+
+    #[test]
+    fn field_requires_an_offset() {
+        let mut interpreter = ForthInterpreter::new();
+
+        assert!(matches!(
+            interpreter.execute("field: lonely"),
+            Err(ForthError::StackUnderflow)
+        ));
+        assert!(matches!(
+            interpreter.execute("begin-structure s field:"),
+            Err(ForthError::MissingName)
+        ));
+    }
+}
@@ -0,0 +1,92 @@
+#[cfg(test)]
+mod values_tests {
+    use crate::{errors::ForthError, ForthInterpreter, Stack};
+
+    #[test]
+    fn value_pushes_contents() {
+        let mut interpreter = ForthInterpreter::new();
+
+        interpreter
+            .execute("42 value answer answer answer +")
+            .unwrap();
+        assert_eq!(interpreter.get_stack_dump(), &Stack::from(vec![84.into()]));
+    }
+
+    #[test]
+    fn to_and_plus_to() {
+        let mut interpreter = ForthInterpreter::new();
+
+        interpreter.execute("1 value x 5 to x x").unwrap();
+        interpreter.execute("10 +to x x").unwrap();
+        assert_eq!(
+            interpreter.get_stack_dump(),
+            &Stack::from(vec![5.into(), 15.into()])
+        );
+    }
+
+    #[test]
+    fn to_inside_definitions() {
+        let mut interpreter = ForthInterpreter::new();
+
+        interpreter
+            .execute("0 value total : add ( n -- ) +to total ; : reset 0 to total ;")
+            .unwrap();
+        interpreter
+            .execute("3 add 4 add total reset total")
+            .unwrap();
+        assert_eq!(
+            interpreter.get_stack_dump(),
+            &Stack::from(vec![7.into(), 0.into()])
+        );
+    }
+
+    #[test]
+    fn value_used_in_definition_sees_updates() {
+        let mut interpreter = ForthInterpreter::new();
+
+        interpreter
+            .execute("2 value factor : scale factor * ;")
+            .unwrap();
+        interpreter
+            .execute("10 scale 3 to factor 10 scale")
+            .unwrap();
+        assert_eq!(
+            interpreter.get_stack_dump(),
+            &Stack::from(vec![20.into(), 30.into()])
+        );
+    }
+
+    #[test]
+    fn values_ignore_redefined_store_words() {
+        let mut interpreter = ForthInterpreter::new();
+
+        interpreter.execute("5 value v : set 10 to v ;").unwrap();
+        interpreter
+            .execute(": @ drop 99 ; : ! drop drop ; : +! drop drop ;")
+            .unwrap();
+        interpreter.execute("v 6 to v v 1 +to v v set v").unwrap();
+        assert_eq!(
+            interpreter.get_stack_dump(),
+            &Stack::from(vec![5.into(), 6.into(), 7.into(), 10.into()])
+        );
+    }
+
+    #[test]
+    fn to_requires_a_value() {
+        let mut interpreter = ForthInterpreter::new();
+
+        interpreter.execute("variable v 5 constant c").unwrap();
+        assert!(matches!(
+            interpreter.execute("1 to v"),
+            Err(ForthError::NotAValue(name)) if name == "v"
+        ));
+        assert!(matches!(
+            interpreter.execute(": bad 1 +to c ;"),
+            Err(ForthError::NotAValue(name)) if name == "c"
+        ));
+        assert!(matches!(
+            interpreter.execute("1 to"),
+            Err(ForthError::MissingName)
+        ));
+    }
+}
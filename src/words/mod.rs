@@ -1,14 +1,47 @@
 use crate::{Pointer, Result};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 pub trait IOWords {
+    /// `.` prints the top of the stack.
+    ///
+    /// # Errors
+    ///
+    /// `StackUnderflow` if the stack is empty.
     fn print_top(&mut self) -> Result<()>;
+
+    /// `emit` prints the character whose code is on top of the stack.
+    ///
+    /// # Errors
+    ///
+    /// `StackUnderflow` if the stack is empty, `InvalidOperands` if the code is not
+    /// a character.
     fn emit(&mut self) -> Result<()>;
+
+    /// `cr` starts a new line of output.
+    ///
+    /// # Errors
+    ///
+    /// Never fails.
     fn cr(&mut self) -> Result<()>;
 
+    /// `key` pushes the code of the next character typed, or nothing if the
+    /// terminal cannot be read.
+    ///
+    /// # Errors
+    ///
+    /// Never fails.
     fn key(&mut self) -> Result<()>;
+
+    /// `word` ( addr char -- ) appends the terminal input other than `char` to the
+    /// array at `addr`.
+    ///
+    /// # Errors
+    ///
+    /// `StackUnderflow` with fewer than two items, `InvalidOperands` if `char` is
+    /// not a character code.
     fn word(&mut self) -> Result<()>;
 
+    #[must_use]
     fn get_words() -> Vec<(String, crate::WordFn)> {
         vec![
             (".".into(), IOWords::print_top as crate::WordFn),
@@ -21,22 +54,111 @@ pub trait IOWords {
 }
 
 pub trait MathWords {
+    /// `+` adds two numbers, wrapping on overflow, or moves a pointer by a number of cells.
+    ///
+    /// # Errors
+    ///
+    /// `StackUnderflow` with fewer than two items, `InvalidOperands` for any other
+    /// operands and `IndexOutOfBound` if a pointer would move before its start.
     fn add(&mut self) -> Result<()>;
+
+    /// `-` subtracts the top number from the one below it, wrapping on overflow.
+    ///
+    /// # Errors
+    ///
+    /// `StackUnderflow` with fewer than two items, `InvalidOperands` unless both
+    /// are numbers.
     fn sub(&mut self) -> Result<()>;
+
+    /// `*` multiplies two numbers, wrapping on overflow.
+    ///
+    /// # Errors
+    ///
+    /// `StackUnderflow` with fewer than two items, `InvalidOperands` unless both
+    /// are numbers.
     fn mul(&mut self) -> Result<()>;
+
+    /// `/` divides the second number by the top one, rounding toward zero.
+    ///
+    /// # Errors
+    ///
+    /// `StackUnderflow` with fewer than two items, `InvalidOperands` unless both
+    /// are numbers and `DivisionByZero` for a zero divisor.
     fn div(&mut self) -> Result<()>;
+
+    /// `mod` leaves the remainder of dividing the second number by the top one.
+    ///
+    /// # Errors
+    ///
+    /// `StackUnderflow` with fewer than two items, `InvalidOperands` unless both
+    /// are numbers and `DivisionByZero` for a zero divisor.
     fn r#mod(&mut self) -> Result<()>;
 
+    /// `negate` changes the sign of the top number, wrapping on overflow.
+    ///
+    /// # Errors
+    ///
+    /// `StackUnderflow` if the stack is empty, `InvalidOperands` unless the top is
+    /// a number.
     fn negate(&mut self) -> Result<()>;
+
+    /// `abs` replaces the top number by its magnitude, wrapping on overflow.
+    ///
+    /// # Errors
+    ///
+    /// `StackUnderflow` if the stack is empty, `InvalidOperands` unless the top is
+    /// a number.
     fn abs(&mut self) -> Result<()>;
+
+    /// `max` keeps the greater of two numbers.
+    ///
+    /// # Errors
+    ///
+    /// `StackUnderflow` with fewer than two items, `InvalidOperands` unless both
+    /// are numbers.
     fn max(&mut self) -> Result<()>;
+
+    /// `min` keeps the lesser of two numbers.
+    ///
+    /// # Errors
+    ///
+    /// `StackUnderflow` with fewer than two items, `InvalidOperands` unless both
+    /// are numbers.
     fn min(&mut self) -> Result<()>;
 
+    /// `+!` ( n addr -- ) adds `n` to the cell at `addr`.
+    ///
+    /// # Errors
+    ///
+    /// `StackUnderflow` with fewer than two items, and otherwise the errors of `@`,
+    /// `+` and `!`.
     fn add_to(&mut self) -> Result<()>;
+
+    /// `-!` ( n addr -- ) subtracts `n` from the cell at `addr`.
+    ///
+    /// # Errors
+    ///
+    /// `StackUnderflow` with fewer than two items, and otherwise the errors of `@`,
+    /// `-` and `!`.
     fn sub_from(&mut self) -> Result<()>;
+
+    /// `*!` ( n addr -- ) multiplies the cell at `addr` by `n`.
+    ///
+    /// # Errors
+    ///
+    /// `StackUnderflow` with fewer than two items, and otherwise the errors of `@`,
+    /// `*` and `!`.
     fn mul_by(&mut self) -> Result<()>;
+
+    /// `/!` ( n addr -- ) divides the cell at `addr` by `n`.
+    ///
+    /// # Errors
+    ///
+    /// `StackUnderflow` with fewer than two items, and otherwise the errors of `@`,
+    /// `/` and `!`.
     fn div_by(&mut self) -> Result<()>;
 
+    #[must_use]
     fn get_words() -> Vec<(String, crate::WordFn)> {
         vec![
             ("+".into(), MathWords::add as crate::WordFn),
@@ -55,18 +177,58 @@ pub trait MathWords {
         ]
     }
 
-    fn add_ptr_offset(pointer: Pointer, offset: i64) -> Pointer;
+    /// Moves `pointer` by `offset` cells.
+    ///
+    /// # Errors
+    ///
+    /// `IndexOutOfBound` if the pointer would move before its start.
+    fn add_ptr_offset(pointer: Pointer, offset: i64) -> Result<Pointer>;
 }
 
 pub trait LogicWords {
+    /// `=` leaves a true flag if the top two items are equal.
+    ///
+    /// # Errors
+    ///
+    /// `StackUnderflow` with fewer than two items.
     fn equal(&mut self) -> Result<()>;
+
+    /// `>` leaves a true flag if the second item is greater than the top one.
+    ///
+    /// # Errors
+    ///
+    /// `StackUnderflow` with fewer than two items.
     fn greater(&mut self) -> Result<()>;
+
+    /// `<` leaves a true flag if the second item is less than the top one.
+    ///
+    /// # Errors
+    ///
+    /// `StackUnderflow` with fewer than two items.
     fn less(&mut self) -> Result<()>;
 
+    /// `invert` turns a zero into a true flag and anything else into a false one.
+    ///
+    /// # Errors
+    ///
+    /// `StackUnderflow` if the stack is empty.
     fn not(&mut self) -> Result<()>;
+
+    /// `and` leaves a true flag if both of the top two items are non-zero.
+    ///
+    /// # Errors
+    ///
+    /// `StackUnderflow` with fewer than two items.
     fn and(&mut self) -> Result<()>;
+
+    /// `or` leaves a true flag if either of the top two items is non-zero.
+    ///
+    /// # Errors
+    ///
+    /// `StackUnderflow` with fewer than two items.
     fn or(&mut self) -> Result<()>;
 
+    #[must_use]
     fn get_words() -> Vec<(String, crate::WordFn)> {
         vec![
             ("=".into(), LogicWords::equal as crate::WordFn),
@@ -80,14 +242,51 @@ pub trait LogicWords {
 }
 
 pub trait StackWords {
+    /// `dup` copies the top item.
+    ///
+    /// # Errors
+    ///
+    /// `StackUnderflow` if the stack is empty.
     fn dup(&mut self) -> Result<()>;
+
+    /// `drop` discards the top item.
+    ///
+    /// # Errors
+    ///
+    /// `StackUnderflow` if the stack is empty.
     fn drop(&mut self) -> Result<()>;
+
+    /// `swap` exchanges the top two items.
+    ///
+    /// # Errors
+    ///
+    /// `StackUnderflow` with fewer than two items.
     fn swap(&mut self) -> Result<()>;
+
+    /// `over` copies the second item to the top.
+    ///
+    /// # Errors
+    ///
+    /// `StackUnderflow` with fewer than two items.
     fn over(&mut self) -> Result<()>;
+
+    /// `rot` moves the third item to the top.
+    ///
+    /// # Errors
+    ///
+    /// `StackUnderflow` with fewer than three items.
     fn rot(&mut self) -> Result<()>;
 
+    /// `@` replaces an address by the contents of its cell.
+    ///
+    /// # Errors
+    ///
+    /// `StackUnderflow` if the stack is empty, `InvalidOperands` unless the top is
+    /// an address, and `VariableNotExist` or `IndexOutOfBound` if no cell lives
+    /// there.
     fn fetch_variable(&mut self) -> Result<()>;
 
+    #[must_use]
     fn get_words() -> Vec<(String, crate::WordFn)> {
         vec![
             ("dup".into(), StackWords::dup as crate::WordFn),
@@ -101,24 +300,1081 @@ pub trait StackWords {
 }
 
 pub trait OtherWords {
+    /// `!` ( x addr -- ) stores `x` in the cell at `addr`.
+    ///
+    /// # Errors
+    ///
+    /// `StackUnderflow` with fewer than two items, `InvalidOperands` unless `addr`
+    /// is an address, and `VariableNotExist` or `IndexOutOfBound` if no cell lives
+    /// there.
     fn store_variable(&mut self) -> Result<()>;
 
+    /// `cells` converts a number of cells to address units.
+    ///
+    /// # Errors
+    ///
+    /// `StackUnderflow` if the stack is empty.
     fn cells(&mut self) -> Result<()>;
+
+    /// `allot` extends the most recent data region by a number of zeroed cells.
+    ///
+    /// # Errors
+    ///
+    /// `StackUnderflow` if the stack is empty, `InvalidOperands` unless the count
+    /// is a non-negative number or if there is no data region.
     fn allot(&mut self) -> Result<()>;
 
+    /// `,` appends the top item to the most recent data region.
+    ///
+    /// # Errors
+    ///
+    /// `StackUnderflow` if the stack is empty, `InvalidOperands` if there is no
+    /// data region.
+    fn comma(&mut self) -> Result<()>;
+
+    #[must_use]
     fn get_words() -> Vec<(String, crate::WordFn)> {
         vec![
             ("!".into(), OtherWords::store_variable as crate::WordFn),
             ("cells".into(), OtherWords::cells as crate::WordFn),
             ("allot".into(), OtherWords::allot as crate::WordFn),
+            (",".into(), OtherWords::comma as crate::WordFn),
+        ]
+    }
+}
+
+pub trait ControlWords {
+    /// `i` pushes the index of the innermost loop.
+    ///
+    /// # Errors
+    ///
+    /// `ReturnStackUnderflow` outside a loop.
+    fn loop_index(&mut self) -> Result<()>;
+
+    /// `j` pushes the index of the loop enclosing the innermost one.
+    ///
+    /// # Errors
+    ///
+    /// `ReturnStackUnderflow` without two nested loops.
+    fn outer_loop_index(&mut self) -> Result<()>;
+
+    /// `k` pushes the index of the third loop outwards.
+    ///
+    /// # Errors
+    ///
+    /// `ReturnStackUnderflow` without three nested loops.
+    fn second_outer_loop_index(&mut self) -> Result<()>;
+
+    /// `leave` ends the innermost loop.
+    ///
+    /// # Errors
+    ///
+    /// Always returns `Leave`, which the loop catches.
+    fn leave(&mut self) -> Result<()>;
+
+    /// `unloop` drops the parameters of the innermost loop so that `exit` may leave it.
+    ///
+    /// # Errors
+    ///
+    /// `ReturnStackUnderflow` outside a loop.
+    fn unloop(&mut self) -> Result<()>;
+
+    /// `recurse` runs the definition being executed again.
+    ///
+    /// # Errors
+    ///
+    /// `RecurseOutsideDefinition` outside a definition, and otherwise any error of
+    /// the definition.
+    fn recurse(&mut self) -> Result<()>;
+
+    /// `exit` returns from the running definition.
+    ///
+    /// # Errors
+    ///
+    /// Always returns `Exit`, which the definition catches.
+    fn exit(&mut self) -> Result<()>;
+
+    #[must_use]
+    fn get_words() -> Vec<(String, crate::WordFn)> {
+        vec![
+            ("i".into(), ControlWords::loop_index as crate::WordFn),
+            ("j".into(), ControlWords::outer_loop_index as crate::WordFn),
+            (
+                "k".into(),
+                ControlWords::second_outer_loop_index as crate::WordFn,
+            ),
+            ("leave".into(), ControlWords::leave as crate::WordFn),
+            ("unloop".into(), ControlWords::unloop as crate::WordFn),
+            ("recurse".into(), ControlWords::recurse as crate::WordFn),
+            ("exit".into(), ControlWords::exit as crate::WordFn),
+        ]
+    }
+}
+
+pub trait DefiningWords {
+    /// `:` starts compiling a definition of the following name.
+    ///
+    /// # Errors
+    ///
+    /// `MissingName` if no name follows.
+    fn colon(&mut self) -> Result<()>;
+
+    /// `;` ends the definition being compiled and adds it to the dictionary, or
+    /// pushes the execution token of a `:noname` definition.
+    ///
+    /// # Errors
+    ///
+    /// `CompileOnly` outside a definition, `ControlStructureMismatch` if a control
+    /// structure is still open.
+    fn semicolon(&mut self) -> Result<()>;
+
+    /// `:noname` starts compiling a definition without a name.
+    ///
+    /// # Errors
+    ///
+    /// Never fails.
+    fn noname(&mut self) -> Result<()>;
+
+    /// `[:` starts a quotation inside the definition being compiled.
+    ///
+    /// # Errors
+    ///
+    /// `CompileOnly` outside a definition.
+    fn begin_quotation(&mut self) -> Result<()>;
+
+    /// `;]` ends a quotation, compiling its execution token as a literal.
+    ///
+    /// # Errors
+    ///
+    /// `CompileOnly` outside a definition, `ControlStructureMismatch` unless a
+    /// quotation is the innermost open construct.
+    fn end_quotation(&mut self) -> Result<()>;
+
+    /// `{:` declares the locals of the definition being compiled, up to `:}`.
+    ///
+    /// # Errors
+    ///
+    /// `CompileOnly` outside a definition, `ControlStructureMismatch` if `:}` is
+    /// missing or the locals are declared inside a control structure or a second
+    /// time.
+    fn locals(&mut self) -> Result<()>;
+
+    /// `variable` defines a word pushing the address of a new cell.
+    ///
+    /// # Errors
+    ///
+    /// `MissingName` if no name follows.
+    fn variable(&mut self) -> Result<()>;
+
+    /// `constant` defines a word pushing the top item.
+    ///
+    /// # Errors
+    ///
+    /// `MissingName` if no name follows, `StackUnderflow` if the stack is empty.
+    fn constant(&mut self) -> Result<()>;
+
+    /// `create` defines a word pushing the address of a new, empty data region.
+    ///
+    /// # Errors
+    ///
+    /// `MissingName` if no name follows.
+    fn create(&mut self) -> Result<()>;
+
+    /// `does>` gives the most recent `create`d word the behavior that follows.
+    ///
+    /// # Errors
+    ///
+    /// `CompileOnly` outside a definition, `ControlStructureMismatch` inside a
+    /// control structure.
+    fn does(&mut self) -> Result<()>;
+
+    #[must_use]
+    fn get_words() -> Vec<(String, crate::WordFn)> {
+        vec![
+            (":".into(), DefiningWords::colon as crate::WordFn),
+            (";".into(), DefiningWords::semicolon as crate::WordFn),
+            (":noname".into(), DefiningWords::noname as crate::WordFn),
+            ("[:".into(), DefiningWords::begin_quotation as crate::WordFn),
+            (";]".into(), DefiningWords::end_quotation as crate::WordFn),
+            ("{:".into(), DefiningWords::locals as crate::WordFn),
+            ("variable".into(), DefiningWords::variable as crate::WordFn),
+            ("constant".into(), DefiningWords::constant as crate::WordFn),
+            ("create".into(), DefiningWords::create as crate::WordFn),
+            ("does>".into(), DefiningWords::does as crate::WordFn),
+        ]
+    }
+
+    #[must_use]
+    fn get_immediate_words() -> Vec<String> {
+        vec![
+            ";".into(),
+            "does>".into(),
+            "[:".into(),
+            ";]".into(),
+            "{:".into(),
+        ]
+    }
+}
+
+/// Words that build control structures in the definition being compiled.
+/// All of them are immediate.
+pub trait CompileWords {
+    /// `if` starts a conditional.
+    ///
+    /// # Errors
+    ///
+    /// `CompileOnly` outside a definition.
+    fn r#if(&mut self) -> Result<()>;
+
+    /// `else` starts the part of a conditional run on a false flag.
+    ///
+    /// # Errors
+    ///
+    /// `CompileOnly` outside a definition, `ControlStructureMismatch` unless an
+    /// `if` is open.
+    fn r#else(&mut self) -> Result<()>;
+
+    /// `then` ends a conditional.
+    ///
+    /// # Errors
+    ///
+    /// `CompileOnly` outside a definition, `ControlStructureMismatch` unless an
+    /// `if` or `else` is open.
+    fn then(&mut self) -> Result<()>;
+
+    /// `do` starts a counted loop.
+    ///
+    /// # Errors
+    ///
+    /// `CompileOnly` outside a definition.
+    fn r#do(&mut self) -> Result<()>;
+
+    /// `?do` starts a counted loop that is skipped if the limit equals the index.
+    ///
+    /// # Errors
+    ///
+    /// `CompileOnly` outside a definition.
+    fn question_do(&mut self) -> Result<()>;
+
+    /// `loop` ends a counted loop, stepping the index by one.
+    ///
+    /// # Errors
+    ///
+    /// `CompileOnly` outside a definition, `ControlStructureMismatch` unless a `do`
+    /// or `?do` is open.
+    fn r#loop(&mut self) -> Result<()>;
+
+    /// `+loop` ends a counted loop, stepping the index by the top number.
+    ///
+    /// # Errors
+    ///
+    /// `CompileOnly` outside a definition, `ControlStructureMismatch` unless a `do`
+    /// or `?do` is open.
+    fn plus_loop(&mut self) -> Result<()>;
+
+    /// `begin` starts an indefinite loop.
+    ///
+    /// # Errors
+    ///
+    /// `CompileOnly` outside a definition.
+    fn begin(&mut self) -> Result<()>;
+
+    /// `until` ends a `begin` loop, repeating it until the flag is true.
+    ///
+    /// # Errors
+    ///
+    /// `CompileOnly` outside a definition, `ControlStructureMismatch` unless a
+    /// `begin` is open.
+    fn until(&mut self) -> Result<()>;
+
+    /// `again` ends a `begin` loop that repeats forever.
+    ///
+    /// # Errors
+    ///
+    /// `CompileOnly` outside a definition, `ControlStructureMismatch` unless a
+    /// `begin` is open.
+    fn again(&mut self) -> Result<()>;
+
+    /// `while` tests the condition of a `begin` loop.
+    ///
+    /// # Errors
+    ///
+    /// `CompileOnly` outside a definition, `ControlStructureMismatch` unless a
+    /// `begin` is open.
+    fn r#while(&mut self) -> Result<()>;
+
+    /// `repeat` ends a `begin ... while` loop.
+    ///
+    /// # Errors
+    ///
+    /// `CompileOnly` outside a definition, `ControlStructureMismatch` unless a
+    /// `while` is open.
+    fn repeat(&mut self) -> Result<()>;
+
+    /// `case` starts a selection between `of` clauses.
+    ///
+    /// # Errors
+    ///
+    /// `CompileOnly` outside a definition.
+    fn case(&mut self) -> Result<()>;
+
+    /// `of` starts a clause run when its value matches the selector.
+    ///
+    /// # Errors
+    ///
+    /// `CompileOnly` outside a definition, `ControlStructureMismatch` unless a
+    /// `case` is open.
+    fn of(&mut self) -> Result<()>;
+
+    /// `endof` ends an `of` clause.
+    ///
+    /// # Errors
+    ///
+    /// `CompileOnly` outside a definition, `ControlStructureMismatch` unless an
+    /// `of` is open.
+    fn endof(&mut self) -> Result<()>;
+
+    /// `endcase` ends a `case`, dropping the selector if no clause matched.
+    ///
+    /// # Errors
+    ///
+    /// `CompileOnly` outside a definition, `ControlStructureMismatch` unless a
+    /// `case` is open.
+    fn endcase(&mut self) -> Result<()>;
+
+    #[must_use]
+    fn get_words() -> Vec<(String, crate::WordFn)> {
+        vec![
+            ("if".into(), CompileWords::r#if as crate::WordFn),
+            ("else".into(), CompileWords::r#else as crate::WordFn),
+            ("then".into(), CompileWords::then as crate::WordFn),
+            ("do".into(), CompileWords::r#do as crate::WordFn),
+            ("?do".into(), CompileWords::question_do as crate::WordFn),
+            ("loop".into(), CompileWords::r#loop as crate::WordFn),
+            ("+loop".into(), CompileWords::plus_loop as crate::WordFn),
+            ("begin".into(), CompileWords::begin as crate::WordFn),
+            ("until".into(), CompileWords::until as crate::WordFn),
+            ("again".into(), CompileWords::again as crate::WordFn),
+            ("while".into(), CompileWords::r#while as crate::WordFn),
+            ("repeat".into(), CompileWords::repeat as crate::WordFn),
+            ("case".into(), CompileWords::case as crate::WordFn),
+            ("of".into(), CompileWords::of as crate::WordFn),
+            ("endof".into(), CompileWords::endof as crate::WordFn),
+            ("endcase".into(), CompileWords::endcase as crate::WordFn),
+        ]
+    }
+
+    #[must_use]
+    fn get_immediate_words() -> Vec<String> {
+        <Self as CompileWords>::get_words()
+            .into_iter()
+            .map(|(name, _)| name)
+            .collect()
+    }
+}
+
+pub trait ValueWords {
+    /// `value` defines a word pushing the contents of a cell initialized from the top item.
+    ///
+    /// # Errors
+    ///
+    /// `MissingName` if no name follows, `StackUnderflow` if the stack is empty.
+    fn value(&mut self) -> Result<()>;
+
+    /// `to` stores the top item in the following value or local.
+    ///
+    /// # Errors
+    ///
+    /// `MissingName` if no name follows, `NotAValue` if it names neither, and
+    /// otherwise the errors of `!`.
+    fn to(&mut self) -> Result<()>;
+
+    /// `+to` adds the top number to the following value or local.
+    ///
+    /// # Errors
+    ///
+    /// `MissingName` if no name follows, `NotAValue` if it names neither, and
+    /// otherwise the errors of `+!`.
+    fn plus_to(&mut self) -> Result<()>;
+
+    #[must_use]
+    fn get_words() -> Vec<(String, crate::WordFn)> {
+        vec![
+            ("value".into(), ValueWords::value as crate::WordFn),
+            ("to".into(), ValueWords::to as crate::WordFn),
+            ("+to".into(), ValueWords::plus_to as crate::WordFn),
+        ]
+    }
+
+    #[must_use]
+    fn get_immediate_words() -> Vec<String> {
+        vec!["to".into(), "+to".into()]
+    }
+}
+
+pub trait ExecutionWords {
+    /// `'` pushes the execution token of the following word.
+    ///
+    /// # Errors
+    ///
+    /// `MissingName` if no name follows, `UnknownWord` if it is not defined.
+    fn tick(&mut self) -> Result<()>;
+
+    /// `[']` compiles the execution token of the following word as a literal.
+    ///
+    /// # Errors
+    ///
+    /// `CompileOnly` while interpreting, `MissingName` if no name follows and
+    /// `UnknownWord` if it is not defined.
+    fn bracket_tick(&mut self) -> Result<()>;
+
+    /// `execute` runs the word whose execution token is on top of the stack.
+    ///
+    /// # Errors
+    ///
+    /// `StackUnderflow` if the stack is empty, `InvalidOperands` unless the top is
+    /// an execution token, and otherwise any error of the word.
+    fn execute(&mut self) -> Result<()>;
+
+    #[must_use]
+    fn get_words() -> Vec<(String, crate::WordFn)> {
+        vec![
+            ("'".into(), ExecutionWords::tick as crate::WordFn),
+            ("[']".into(), ExecutionWords::bracket_tick as crate::WordFn),
+            ("execute".into(), ExecutionWords::execute as crate::WordFn),
+        ]
+    }
+
+    #[must_use]
+    fn get_immediate_words() -> Vec<String> {
+        vec!["[']".into()]
+    }
+}
+
+/// Words whose behavior is another word, given by its execution token or named by a
+/// string, that can be replaced at any time.
+pub trait DeferWords {
+    /// `defer` defines a deferred word with no action yet.
+    ///
+    /// # Errors
+    ///
+    /// `MissingName` if no name follows.
+    fn defer(&mut self) -> Result<()>;
+
+    /// `is` makes the word given on the stack the action of the following deferred word.
+    ///
+    /// # Errors
+    ///
+    /// `MissingName` if no name follows, `NotADefer` if it is not a deferred word,
+    /// and otherwise the errors of `defer!`.
+    fn is(&mut self) -> Result<()>;
+
+    /// `action-of` pushes the action of the following deferred word.
+    ///
+    /// # Errors
+    ///
+    /// `MissingName` if no name follows, `NotADefer` if it is not a deferred word,
+    /// and otherwise the errors of `defer@`.
+    fn action_of(&mut self) -> Result<()>;
+
+    /// `defer@` pushes the action of a deferred word given by its execution token or name.
+    ///
+    /// # Errors
+    ///
+    /// `StackUnderflow` or `InvalidOperands` if the deferred word is missing from
+    /// the stack, `NotADefer` if it is not a deferred word and `UninitializedDefer`
+    /// if it has no action yet.
+    fn defer_fetch(&mut self) -> Result<()>;
+
+    /// `defer!` sets the action of a deferred word; both are given by execution
+    /// token or name.
+    ///
+    /// # Errors
+    ///
+    /// `StackUnderflow` or `InvalidOperands` if either is missing from the stack,
+    /// `NotADefer` if the target is not a deferred word and `UnknownWord` if the
+    /// action names no word.
+    fn defer_store(&mut self) -> Result<()>;
+
+    #[must_use]
+    fn get_words() -> Vec<(String, crate::WordFn)> {
+        vec![
+            ("defer".into(), DeferWords::defer as crate::WordFn),
+            ("is".into(), DeferWords::is as crate::WordFn),
+            ("action-of".into(), DeferWords::action_of as crate::WordFn),
+            ("defer@".into(), DeferWords::defer_fetch as crate::WordFn),
+            ("defer!".into(), DeferWords::defer_store as crate::WordFn),
+        ]
+    }
+
+    #[must_use]
+    fn get_immediate_words() -> Vec<String> {
+        vec!["is".into(), "action-of".into()]
+    }
+}
+
+/// Words that discard definitions together with everything defined after them.
+pub trait DictionaryWords {
+    /// `marker` defines a word that forgets itself and everything defined after it.
+    ///
+    /// # Errors
+    ///
+    /// `MissingName` if no name follows.
+    fn marker(&mut self) -> Result<()>;
+
+    /// `forget` discards the following word and everything defined after it.
+    ///
+    /// # Errors
+    ///
+    /// `MissingName` if no name follows, `UnknownWord` if it is not defined.
+    fn forget(&mut self) -> Result<()>;
+
+    #[must_use]
+    fn get_words() -> Vec<(String, crate::WordFn)> {
+        vec![
+            ("marker".into(), DictionaryWords::marker as crate::WordFn),
+            ("forget".into(), DictionaryWords::forget as crate::WordFn),
+        ]
+    }
+}
+
+/// Words that inspect or switch the interpreter state while compiling.
+pub trait StateWords {
+    /// `immediate` makes the most recent named definition run while compiling.
+    ///
+    /// # Errors
+    ///
+    /// `MissingName` if there is none, or if a `:noname` definition came after it.
+    fn immediate(&mut self) -> Result<()>;
+
+    /// `state` pushes the address of the cell telling whether the interpreter is compiling.
+    ///
+    /// # Errors
+    ///
+    /// Never fails.
+    fn state(&mut self) -> Result<()>;
+
+    /// `[` switches to interpreting.
+    ///
+    /// # Errors
+    ///
+    /// Never fails.
+    fn left_bracket(&mut self) -> Result<()>;
+
+    /// `]` switches back to compiling the open definition.
+    ///
+    /// # Errors
+    ///
+    /// `CompileOnly` if no definition is open.
+    fn right_bracket(&mut self) -> Result<()>;
+
+    /// `literal` compiles the top item into the definition as a literal.
+    ///
+    /// # Errors
+    ///
+    /// `CompileOnly` while interpreting, `StackUnderflow` if the stack is empty.
+    fn literal(&mut self) -> Result<()>;
+
+    /// `postpone` adds the compilation behavior of the following word to the definition.
+    ///
+    /// # Errors
+    ///
+    /// `CompileOnly` while interpreting, `MissingName` if no name follows and
+    /// `UnknownWord` if it is not defined.
+    fn postpone(&mut self) -> Result<()>;
+
+    #[must_use]
+    fn get_words() -> Vec<(String, crate::WordFn)> {
+        vec![
+            ("immediate".into(), StateWords::immediate as crate::WordFn),
+            ("state".into(), StateWords::state as crate::WordFn),
+            ("[".into(), StateWords::left_bracket as crate::WordFn),
+            ("]".into(), StateWords::right_bracket as crate::WordFn),
+            ("literal".into(), StateWords::literal as crate::WordFn),
+            ("postpone".into(), StateWords::postpone as crate::WordFn),
+        ]
+    }
+
+    #[must_use]
+    fn get_immediate_words() -> Vec<String> {
+        vec!["[".into(), "literal".into(), "postpone".into()]
+    }
+}
+
+/// Words that consume the input following them.
+pub trait ParsingWords {
+    /// `(` skips a comment up to `)`, which may close on a later line.
+    ///
+    /// # Errors
+    ///
+    /// Never fails.
+    fn paren(&mut self) -> Result<()>;
+
+    /// `\` skips the rest of the line.
+    ///
+    /// # Errors
+    ///
+    /// Never fails.
+    fn backslash(&mut self) -> Result<()>;
+
+    /// `parse-name` pushes the (addr len) of the next name in the input, empty at its end.
+    ///
+    /// # Errors
+    ///
+    /// Never fails.
+    fn parse_name(&mut self) -> Result<()>;
+
+    /// `parse` ( char -- addr len ) takes the input up to `char`.
+    ///
+    /// # Errors
+    ///
+    /// `StackUnderflow` if the stack is empty, `InvalidOperands` unless the top is
+    /// a character code.
+    fn parse(&mut self) -> Result<()>;
+
+    /// `>in` pushes the address of the cell holding the input position.
+    ///
+    /// # Errors
+    ///
+    /// Never fails.
+    fn to_in(&mut self) -> Result<()>;
+
+    /// `source` pushes the (addr len) of the current input.
+    ///
+    /// # Errors
+    ///
+    /// Never fails.
+    fn source(&mut self) -> Result<()>;
+
+    /// `char` pushes the code of the first character of the following name.
+    ///
+    /// # Errors
+    ///
+    /// `MissingName` if no name follows.
+    fn char(&mut self) -> Result<()>;
+
+    /// `[char]` compiles the code of the first character of the following name as a
+    /// literal.
+    ///
+    /// # Errors
+    ///
+    /// `CompileOnly` while interpreting, `MissingName` if no name follows.
+    fn bracket_char(&mut self) -> Result<()>;
+
+    #[must_use]
+    fn get_words() -> Vec<(String, crate::WordFn)> {
+        vec![
+            ("(".into(), ParsingWords::paren as crate::WordFn),
+            ("\\".into(), ParsingWords::backslash as crate::WordFn),
+            (
+                "parse-name".into(),
+                ParsingWords::parse_name as crate::WordFn,
+            ),
+            ("parse".into(), ParsingWords::parse as crate::WordFn),
+            (">in".into(), ParsingWords::to_in as crate::WordFn),
+            ("source".into(), ParsingWords::source as crate::WordFn),
+            ("char".into(), ParsingWords::char as crate::WordFn),
+            ("[char]".into(), ParsingWords::bracket_char as crate::WordFn),
+        ]
+    }
+
+    #[must_use]
+    fn get_immediate_words() -> Vec<String> {
+        vec!["(".into(), "\\".into(), "[char]".into()]
+    }
+}
+
+/// Words that switch the input source to other text and back.
+pub trait SourceWords {
+    /// `evaluate` interprets an (addr len) string as source text.
+    ///
+    /// # Errors
+    ///
+    /// `StackUnderflow` or `InvalidOperands` without a string on the stack,
+    /// `ReturnStackOverflow` if source texts nest too deeply, and otherwise the
+    /// error of the text.
+    fn evaluate(&mut self) -> Result<()>;
+
+    /// `include` interprets the file named by the following name.
+    ///
+    /// # Errors
+    ///
+    /// `MissingName` if no name follows, `CannotInclude` if the file cannot be read
+    /// or is already being included, and otherwise the error of the file wrapped in
+    /// `InFile`.
+    fn include(&mut self) -> Result<()>;
+
+    /// `included` interprets the file named by an (addr len) string.
+    ///
+    /// # Errors
+    ///
+    /// `StackUnderflow` or `InvalidOperands` without a string on the stack,
+    /// `CannotInclude` if the file cannot be read or is already being included, and
+    /// otherwise the error of the file wrapped in `InFile`.
+    fn included(&mut self) -> Result<()>;
+
+    /// `require` is `include` for a file that has not been included yet.
+    ///
+    /// # Errors
+    ///
+    /// The errors of `include`.
+    fn require(&mut self) -> Result<()>;
+
+    /// `required` is `included` for a file that has not been included yet.
+    ///
+    /// # Errors
+    ///
+    /// The errors of `included`.
+    fn required(&mut self) -> Result<()>;
+
+    #[must_use]
+    fn get_words() -> Vec<(String, crate::WordFn)> {
+        vec![
+            ("evaluate".into(), SourceWords::evaluate as crate::WordFn),
+            ("include".into(), SourceWords::include as crate::WordFn),
+            ("included".into(), SourceWords::included as crate::WordFn),
+            ("require".into(), SourceWords::require as crate::WordFn),
+            ("required".into(), SourceWords::required as crate::WordFn),
+        ]
+    }
+}
+
+/// String literal words. The parsing ones are immediate and compile their string
+/// into the current definition.
+pub trait StringWords {
+    /// `."` prints the text up to `"`, or compiles printing it.
+    ///
+    /// # Errors
+    ///
+    /// Never fails.
+    fn dot_quote(&mut self) -> Result<()>;
+
+    /// `.(` prints the text up to `)`.
+    ///
+    /// # Errors
+    ///
+    /// Never fails.
+    fn dot_paren(&mut self) -> Result<()>;
+
+    /// `s"` pushes or compiles the (addr len) of the text up to `"`.
+    ///
+    /// # Errors
+    ///
+    /// Never fails.
+    fn s_quote(&mut self) -> Result<()>;
+
+    /// `s\"` is `s"` with escape sequences translated.
+    ///
+    /// # Errors
+    ///
+    /// `InvalidOperands` for an unknown escape sequence.
+    fn s_backslash_quote(&mut self) -> Result<()>;
+
+    /// `c"` pushes or compiles the address of the text up to `"`, stored after its length.
+    ///
+    /// # Errors
+    ///
+    /// Never fails.
+    fn c_quote(&mut self) -> Result<()>;
+
+    /// `type` prints an (addr len) string.
+    ///
+    /// # Errors
+    ///
+    /// `StackUnderflow` or `InvalidOperands` without a string of character codes on
+    /// the stack.
+    fn r#type(&mut self) -> Result<()>;
+
+    /// `count` turns the address of a counted string into its (addr len).
+    ///
+    /// # Errors
+    ///
+    /// `StackUnderflow` if the stack is empty, `InvalidOperands` unless the top is
+    /// an address, and `VariableNotExist` or `IndexOutOfBound` if no cell lives
+    /// there.
+    fn count(&mut self) -> Result<()>;
+
+    #[must_use]
+    fn get_words() -> Vec<(String, crate::WordFn)> {
+        vec![
+            (".\"".into(), StringWords::dot_quote as crate::WordFn),
+            (".(".into(), StringWords::dot_paren as crate::WordFn),
+            ("s\"".into(), StringWords::s_quote as crate::WordFn),
+            (
+                "s\\\"".into(),
+                StringWords::s_backslash_quote as crate::WordFn,
+            ),
+            ("c\"".into(), StringWords::c_quote as crate::WordFn),
+            ("type".into(), StringWords::r#type as crate::WordFn),
+            ("count".into(), StringWords::count as crate::WordFn),
+        ]
+    }
+
+    #[must_use]
+    fn get_immediate_words() -> Vec<String> {
+        vec![
+            ".\"".into(),
+            ".(".into(),
+            "s\"".into(),
+            "s\\\"".into(),
+            "c\"".into(),
+        ]
+    }
+}
+
+pub trait NumberWords {
+    /// `base` pushes the address of the cell holding the number base.
+    ///
+    /// # Errors
+    ///
+    /// Never fails.
+    fn base(&mut self) -> Result<()>;
+
+    /// `hex` sets the number base to sixteen.
+    ///
+    /// # Errors
+    ///
+    /// Never fails.
+    fn hex(&mut self) -> Result<()>;
+
+    /// `decimal` sets the number base to ten.
+    ///
+    /// # Errors
+    ///
+    /// Never fails.
+    fn decimal(&mut self) -> Result<()>;
+
+    /// `binary` sets the number base to two.
+    ///
+    /// # Errors
+    ///
+    /// Never fails.
+    fn binary(&mut self) -> Result<()>;
+
+    #[must_use]
+    fn get_words() -> Vec<(String, crate::WordFn)> {
+        vec![
+            ("base".into(), NumberWords::base as crate::WordFn),
+            ("hex".into(), NumberWords::hex as crate::WordFn),
+            ("decimal".into(), NumberWords::decimal as crate::WordFn),
+            ("binary".into(), NumberWords::binary as crate::WordFn),
+        ]
+    }
+}
+
+/// Words that lay out records as named field offsets.
+pub trait StructureWords {
+    /// `begin-structure` defines a word pushing the size of the structure that
+    /// follows, and pushes what `end-structure` needs to set it.
+    ///
+    /// # Errors
+    ///
+    /// `MissingName` if no name follows.
+    fn begin_structure(&mut self) -> Result<()>;
+
+    /// `end-structure` ( addr size -- ) sets the size of the structure begun with
+    /// `begin-structure`.
+    ///
+    /// # Errors
+    ///
+    /// `StackUnderflow` with fewer than two items, `InvalidOperands` unless they
+    /// came from `begin-structure` and the fields.
+    fn end_structure(&mut self) -> Result<()>;
+
+    /// `+field` ( offset size -- offset' ) defines a word adding `offset` to an address.
+    ///
+    /// # Errors
+    ///
+    /// `MissingName` if no name follows, `StackUnderflow` with fewer than two items
+    /// and `InvalidOperands` unless both are numbers.
+    fn plus_field(&mut self) -> Result<()>;
+
+    /// `field:` is `+field` for a field of one cell.
+    ///
+    /// # Errors
+    ///
+    /// The errors of `+field`.
+    fn field(&mut self) -> Result<()>;
+
+    #[must_use]
+    fn get_words() -> Vec<(String, crate::WordFn)> {
+        vec![
+            (
+                "begin-structure".into(),
+                StructureWords::begin_structure as crate::WordFn,
+            ),
+            (
+                "end-structure".into(),
+                StructureWords::end_structure as crate::WordFn,
+            ),
+            ("+field".into(), StructureWords::plus_field as crate::WordFn),
+            ("field:".into(), StructureWords::field as crate::WordFn),
+        ]
+    }
+}
+
+/// Words that create word lists and choose which of them are searched and which
+/// one receives new definitions.
+pub trait SearchOrderWords {
+    /// `forth-wordlist` pushes the identifier of the word list holding the standard words.
+    ///
+    /// # Errors
+    ///
+    /// Never fails.
+    fn forth_wordlist(&mut self) -> Result<()>;
+
+    /// `wordlist` creates an empty word list and pushes its identifier.
+    ///
+    /// # Errors
+    ///
+    /// Never fails.
+    fn wordlist(&mut self) -> Result<()>;
+
+    /// `get-current` pushes the word list new definitions go into.
+    ///
+    /// # Errors
+    ///
+    /// Never fails.
+    fn get_current(&mut self) -> Result<()>;
+
+    /// `set-current` makes the word list on top of the stack receive new definitions.
+    ///
+    /// # Errors
+    ///
+    /// `StackUnderflow` if the stack is empty, `InvalidOperands` unless the top is
+    /// a word list.
+    fn set_current(&mut self) -> Result<()>;
+
+    /// `get-order` pushes the word lists of the search order, first one on top, and
+    /// their count.
+    ///
+    /// # Errors
+    ///
+    /// Never fails.
+    fn get_order(&mut self) -> Result<()>;
+
+    /// `set-order` replaces the search order by a count of word lists, or resets it
+    /// for a count of -1.
+    ///
+    /// # Errors
+    ///
+    /// `StackUnderflow` if the stack holds fewer word lists than the count,
+    /// `InvalidOperands` for a negative count other than -1 or anything but word
+    /// lists.
+    fn set_order(&mut self) -> Result<()>;
+
+    /// `also` duplicates the first word list of the search order.
+    ///
+    /// # Errors
+    ///
+    /// `SearchOrderUnderflow` if the search order is empty.
+    fn also(&mut self) -> Result<()>;
+
+    /// `only` resets the search order to the FORTH word list alone.
+    ///
+    /// # Errors
+    ///
+    /// Never fails.
+    fn only(&mut self) -> Result<()>;
+
+    /// `previous` removes the first word list from the search order.
+    ///
+    /// # Errors
+    ///
+    /// `SearchOrderUnderflow` if the search order is empty.
+    fn previous(&mut self) -> Result<()>;
+
+    /// `forth` replaces the first word list of the search order by the FORTH word list.
+    ///
+    /// # Errors
+    ///
+    /// `SearchOrderUnderflow` if the search order is empty.
+    fn forth(&mut self) -> Result<()>;
+
+    /// `definitions` makes the first word list of the search order receive new definitions.
+    ///
+    /// # Errors
+    ///
+    /// `SearchOrderUnderflow` if the search order is empty.
+    fn definitions(&mut self) -> Result<()>;
+
+    /// `vocabulary` defines a word replacing the first word list of the search
+    /// order by a new one.
+    ///
+    /// # Errors
+    ///
+    /// `MissingName` if no name follows.
+    fn vocabulary(&mut self) -> Result<()>;
+
+    #[must_use]
+    fn get_words() -> Vec<(String, crate::WordFn)> {
+        vec![
+            (
+                "forth-wordlist".into(),
+                SearchOrderWords::forth_wordlist as crate::WordFn,
+            ),
+            (
+                "wordlist".into(),
+                SearchOrderWords::wordlist as crate::WordFn,
+            ),
+            (
+                "get-current".into(),
+                SearchOrderWords::get_current as crate::WordFn,
+            ),
+            (
+                "set-current".into(),
+                SearchOrderWords::set_current as crate::WordFn,
+            ),
+            (
+                "get-order".into(),
+                SearchOrderWords::get_order as crate::WordFn,
+            ),
+            (
+                "set-order".into(),
+                SearchOrderWords::set_order as crate::WordFn,
+            ),
+            ("also".into(), SearchOrderWords::also as crate::WordFn),
+            ("only".into(), SearchOrderWords::only as crate::WordFn),
+            (
+                "previous".into(),
+                SearchOrderWords::previous as crate::WordFn,
+            ),
+            ("forth".into(), SearchOrderWords::forth as crate::WordFn),
+            (
+                "definitions".into(),
+                SearchOrderWords::definitions as crate::WordFn,
+            ),
+            (
+                "vocabulary".into(),
+                SearchOrderWords::vocabulary as crate::WordFn,
+            ),
         ]
     }
 }
 
 pub trait StandardWords
 where
-    Self: IOWords + MathWords + LogicWords + StackWords + OtherWords,
+    Self: IOWords
+        + MathWords
+        + LogicWords
+        + StackWords
+        + OtherWords
+        + ControlWords
+        + DefiningWords
+        + ValueWords
+        + ExecutionWords
+        + DeferWords
+        + DictionaryWords
+        + CompileWords
+        + StateWords
+        + ParsingWords
+        + SourceWords
+        + StringWords
+        + NumberWords
+        + StructureWords
+        + SearchOrderWords,
 {
+    #[must_use]
     fn get_words() -> HashMap<String, crate::WordFn> {
         <Self as IOWords>::get_words()
             .iter()
@@ -126,7 +1382,35 @@ where
             .chain(<Self as LogicWords>::get_words().iter())
             .chain(<Self as StackWords>::get_words().iter())
             .chain(<Self as OtherWords>::get_words().iter())
+            .chain(<Self as ControlWords>::get_words().iter())
+            .chain(<Self as DefiningWords>::get_words().iter())
+            .chain(<Self as ValueWords>::get_words().iter())
+            .chain(<Self as ExecutionWords>::get_words().iter())
+            .chain(<Self as DeferWords>::get_words().iter())
+            .chain(<Self as DictionaryWords>::get_words().iter())
+            .chain(<Self as CompileWords>::get_words().iter())
+            .chain(<Self as StateWords>::get_words().iter())
+            .chain(<Self as ParsingWords>::get_words().iter())
+            .chain(<Self as SourceWords>::get_words().iter())
+            .chain(<Self as StringWords>::get_words().iter())
+            .chain(<Self as NumberWords>::get_words().iter())
+            .chain(<Self as StructureWords>::get_words().iter())
+            .chain(<Self as SearchOrderWords>::get_words().iter())
             .cloned()
             .collect()
     }
+
+    #[must_use]
+    fn get_immediate_words() -> HashSet<String> {
+        <Self as DefiningWords>::get_immediate_words()
+            .into_iter()
+            .chain(<Self as ValueWords>::get_immediate_words())
+            .chain(<Self as ExecutionWords>::get_immediate_words())
+            .chain(<Self as DeferWords>::get_immediate_words())
+            .chain(<Self as CompileWords>::get_immediate_words())
+            .chain(<Self as StateWords>::get_immediate_words())
+            .chain(<Self as ParsingWords>::get_immediate_words())
+            .chain(<Self as StringWords>::get_immediate_words())
+            .collect()
+    }
 }